@@ -0,0 +1,496 @@
+use std::{fmt::Display, fs, io, path::Path};
+
+use crate::error::{numbered_lines, parse_number, AocError};
+use crate::solution::{Answer, Result};
+
+// the answer key lives next to the workspace's Cargo.toml
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+// 64 bit FNV-1a, enough to tell different people's inputs apart without pulling in a hashing crate
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
+
+// the hash an input is recorded under, line endings and trailing whitespace don't change it
+pub fn input_hash(raw_input: &str) -> String {
+    let normalised = raw_input.replace("\r\n", "\n");
+    format!("{:016x}", fnv1a(normalised.trim_end().as_bytes()))
+}
+
+// the expected answer for one part of a day, run against one particular input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
+
+// Every recorded answer, read from and written to a small subset of TOML:
+//
+//      [[answer]]
+//      day = 1
+//      part = 2
+//      input = "af63bd4c8601b7df"
+//      answer = "1523"
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnswerKey {
+    answers: Vec<RecordedAnswer>,
+}
+
+impl AnswerKey {
+    pub fn parse(raw: &str) -> Result<Self> {
+        let mut answers = Vec::new();
+        let mut current: Option<PartialAnswer> = None;
+
+        for (line_number, line) in numbered_lines(raw) {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if line == "[[answer]]" {
+                if let Some(partial) = current.take() {
+                    answers.push(partial.finish()?);
+                }
+                current = Some(PartialAnswer::new(line_number));
+                continue;
+            }
+
+            let partial = current
+                .as_mut()
+                .ok_or_else(|| AocError::parse("[[answer]]", line).at_line(line_number))?;
+            partial.set(line).map_err(|err| err.at_line(line_number))?;
+        }
+
+        if let Some(partial) = current {
+            answers.push(partial.finish()?);
+        }
+
+        Ok(Self { answers })
+    }
+
+    // a missing file is just an empty key, so verifying works before anything is recorded
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(raw) => Self::parse(&raw).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", path.display(), err),
+                )
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn lookup(&self, day: u8, part: u8, input: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|recorded| {
+                recorded.day == day && recorded.part == part && recorded.input == input
+            })
+            .map(|recorded| recorded.answer.as_str())
+    }
+
+    // add or replace the answer for a day, part and input, keeping the key in puzzle order
+    pub fn record(&mut self, day: u8, part: u8, input: &str, answer: &Answer) {
+        let answer = answer.to_string();
+        match self.answers.iter_mut().find(|recorded| {
+            recorded.day == day && recorded.part == part && recorded.input == input
+        }) {
+            Some(recorded) => recorded.answer = answer,
+            None => self.answers.push(RecordedAnswer {
+                day,
+                part,
+                input: input.to_string(),
+                answer,
+            }),
+        }
+        self.answers
+            .sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+    }
+
+    pub fn answers(&self) -> &[RecordedAnswer] {
+        &self.answers
+    }
+}
+
+impl Display for AnswerKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# expected answers, checked by `aoc verify`")?;
+        for recorded in &self.answers {
+            writeln!(f)?;
+            writeln!(f, "[[answer]]")?;
+            writeln!(f, "day = {}", recorded.day)?;
+            writeln!(f, "part = {}", recorded.part)?;
+            writeln!(f, "input = {}", quote(&recorded.input))?;
+            writeln!(f, "answer = {}", quote(&recorded.answer))?;
+        }
+        Ok(())
+    }
+}
+
+// an [[answer]] table that's still being read
+struct PartialAnswer {
+    line: usize,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    answer: Option<String>,
+}
+
+impl PartialAnswer {
+    fn new(line: usize) -> Self {
+        Self {
+            line,
+            day: None,
+            part: None,
+            input: None,
+            answer: None,
+        }
+    }
+
+    // key = value
+    fn set(&mut self, line: &str) -> Result<()> {
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| AocError::parse("a line like key = value", line))?;
+        // where the value starts, past the = and any spaces
+        let value_column = key.len() + 2 + value.len() - value.trim_start().len();
+
+        match key.trim() {
+            "day" => {
+                self.day = Some(
+                    parse_number(value, "a day number")
+                        .map_err(|err| err.at_column(value_column))?,
+                )
+            }
+            "part" => {
+                self.part = Some(
+                    parse_number(value, "a part number")
+                        .map_err(|err| err.at_column(value_column))?,
+                )
+            }
+            "input" => {
+                self.input = Some(unquote(value).map_err(|err| err.at_column(value_column))?)
+            }
+            "answer" => {
+                self.answer = Some(unquote(value).map_err(|err| err.at_column(value_column))?)
+            }
+            other => return Err(AocError::parse("day, part, input or answer", other).at_column(1)),
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<RecordedAnswer> {
+        let missing = |key: &str| {
+            AocError::malformed(format!(
+                "the answer starting on line {} has no {}",
+                self.line, key
+            ))
+        };
+
+        Ok(RecordedAnswer {
+            day: self.day.ok_or_else(|| missing("day"))?,
+            part: self.part.ok_or_else(|| missing("part"))?,
+            input: self.input.clone().ok_or_else(|| missing("input"))?,
+            answer: self.answer.clone().ok_or_else(|| missing("answer"))?,
+        })
+    }
+}
+
+// a # outside of a quoted string starts a comment
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => (),
+        }
+    }
+    line
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn unquote(value: &str) -> Result<String> {
+    let inner = value
+        .trim()
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .ok_or_else(|| AocError::parse("a quoted string", value.trim()))?;
+
+    let mut unquoted = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(escaped @ ('\\' | '"')) => unquoted.push(escaped),
+                Some('n') => unquoted.push('\n'),
+                other => {
+                    return Err(AocError::parse(
+                        "an escaped \\ or \"",
+                        other.map(String::from).unwrap_or_default(),
+                    ))
+                }
+            },
+            '"' => return Err(AocError::parse("a quoted string", value.trim())),
+            _ => unquoted.push(c),
+        }
+    }
+    Ok(unquoted)
+}
+
+// how a puzzle's answer compared to the one on record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+    // nothing recorded for this input yet, the answer that was found is kept for recording
+    Missing { actual: String },
+    // the input couldn't be read or solved
+    Error(String),
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Fail { .. } | Outcome::Error(_))
+    }
+}
+
+// the result of checking one part of one day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verification {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub outcome: Outcome,
+}
+
+impl Verification {
+    pub fn check(day: u8, part: u8, input: &str, result: Result<Answer>, key: &AnswerKey) -> Self {
+        let outcome = match (result, key.lookup(day, part, input)) {
+            (Err(err), _) => Outcome::Error(err.to_string()),
+            (Ok(answer), None) => Outcome::Missing {
+                actual: answer.to_string(),
+            },
+            (Ok(answer), Some(expected)) if answer.to_string() == expected => Outcome::Pass,
+            (Ok(answer), Some(expected)) => Outcome::Fail {
+                expected: expected.to_string(),
+                actual: answer.to_string(),
+            },
+        };
+
+        Self {
+            day,
+            part,
+            input: input.to_string(),
+            outcome,
+        }
+    }
+}
+
+// a table of verifications, one row each, with a summary line at the bottom
+pub fn format_table(verifications: &[Verification]) -> String {
+    let rows = verifications
+        .iter()
+        .map(|verification| {
+            let (status, expected, actual) = match &verification.outcome {
+                Outcome::Pass => ("pass", "", ""),
+                Outcome::Fail { expected, actual } => ("FAIL", expected.as_str(), actual.as_str()),
+                Outcome::Missing { actual } => ("missing", "", actual.as_str()),
+                Outcome::Error(message) => ("error", "", message.as_str()),
+            };
+            [
+                verification.day.to_string(),
+                verification.part.to_string(),
+                verification.input.clone(),
+                status.to_string(),
+                expected.to_string(),
+                actual.to_string(),
+            ]
+        })
+        .collect::<Vec<[String; 6]>>();
+
+    let headings = ["day", "part", "input", "result", "expected", "actual"];
+    let mut widths = headings.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: &[&str]| {
+        cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<String>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut table = vec![format_row(&headings)];
+    for row in &rows {
+        table.push(format_row(&row.each_ref().map(String::as_str)));
+    }
+
+    let count = |status: fn(&Outcome) -> bool| {
+        verifications
+            .iter()
+            .filter(|verification| status(&verification.outcome))
+            .count()
+    };
+    table.push(format!(
+        "{} passed, {} failed, {} missing, {} errors",
+        count(|outcome| matches!(outcome, Outcome::Pass)),
+        count(|outcome| matches!(outcome, Outcome::Fail { .. })),
+        count(|outcome| matches!(outcome, Outcome::Missing { .. })),
+        count(|outcome| matches!(outcome, Outcome::Error(_))),
+    ));
+
+    table.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_key() -> String {
+        let test_data = [
+            "# expected answers",
+            "",
+            "[[answer]]",
+            "day = 1",
+            "part = 1",
+            "input = \"0123456789abcdef\" # alice",
+            "answer = \"1692\"",
+            "",
+            "[[answer]]",
+            "day = 13",
+            "part = 2",
+            "input = \"0123456789abcdef\"",
+            "answer = \"say \\\"hi\\\" # not a comment\"",
+        ];
+        test_data.join("\n")
+    }
+
+    #[test]
+    fn fnv_hashes() {
+        // the reference values for 64 bit FNV-1a
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(input_hash("199\r\n200\n\n"), input_hash("199\n200"));
+        assert_ne!(input_hash("199\n200"), input_hash("199\n201"));
+    }
+
+    #[test]
+    fn parse_key() -> Result<()> {
+        let key = AnswerKey::parse(&example_key())?;
+
+        assert_eq!(key.answers().len(), 2);
+        assert_eq!(key.lookup(1, 1, "0123456789abcdef"), Some("1692"));
+        assert_eq!(
+            key.lookup(13, 2, "0123456789abcdef"),
+            Some("say \"hi\" # not a comment")
+        );
+        assert_eq!(key.lookup(1, 2, "0123456789abcdef"), None);
+        assert_eq!(key.lookup(1, 1, "fedcba9876543210"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> Result<()> {
+        let mut key = AnswerKey::parse(&example_key())?;
+        key.record(1, 2, "0123456789abcdef", &Answer::UInt(1523));
+        key.record(1, 1, "0123456789abcdef", &Answer::UInt(1693));
+
+        assert_eq!(AnswerKey::parse(&key.to_string())?, key);
+        assert_eq!(key.answers()[1].answer, "1523");
+        assert_eq!(key.lookup(1, 1, "0123456789abcdef"), Some("1693"));
+        Ok(())
+    }
+
+    #[test]
+    fn bad_keys() {
+        assert_eq!(
+            AnswerKey::parse("day = 1").err(),
+            Some(AocError::parse("[[answer]]", "day = 1").at_line(1))
+        );
+        assert_eq!(
+            AnswerKey::parse("[[answer]]\nday = one").err(),
+            Some(
+                AocError::parse("a day number", "one")
+                    .at_line(2)
+                    .at_column(7)
+            )
+        );
+        assert_eq!(
+            AnswerKey::parse("[[answer]]\nanswer = 12").err(),
+            Some(
+                AocError::parse("a quoted string", "12")
+                    .at_line(2)
+                    .at_column(10)
+            )
+        );
+        assert!(matches!(
+            AnswerKey::parse("[[answer]]\nday = 1\npart = 1\nanswer = \"2\""),
+            Err(AocError::MalformedInput(_))
+        ));
+    }
+
+    #[test]
+    fn verify_outcomes() -> Result<()> {
+        let key = AnswerKey::parse(&example_key())?;
+        let input = "0123456789abcdef";
+
+        let pass = Verification::check(1, 1, input, Ok(Answer::UInt(1692)), &key);
+        assert_eq!(pass.outcome, Outcome::Pass);
+
+        let fail = Verification::check(1, 1, input, Ok(Answer::UInt(1)), &key);
+        assert!(fail.outcome.is_failure());
+
+        let missing = Verification::check(2, 1, input, Ok(Answer::UInt(1)), &key);
+        assert_eq!(
+            missing.outcome,
+            Outcome::Missing {
+                actual: String::from("1")
+            }
+        );
+        assert!(!missing.outcome.is_failure());
+
+        let error = Verification::check(2, 2, input, Err(AocError::unsolvable("nope")), &key);
+        assert!(error.outcome.is_failure());
+
+        assert_eq!(
+            format_table(&[pass, fail, missing, error]),
+            [
+                "day  part  input             result   expected  actual",
+                "1    1     0123456789abcdef  pass",
+                "1    1     0123456789abcdef  FAIL     1692      1",
+                "2    1     0123456789abcdef  missing            1",
+                "2    2     0123456789abcdef  error              unsolvable: nope",
+                "1 passed, 1 failed, 1 missing, 1 errors",
+            ]
+            .join("\n")
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,381 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::error::{numbered_lines, parse_number, AocError};
+use crate::solution::{Result, Timing};
+use crate::solve_timed;
+
+// the input label used for a day's worked example, real inputs are labelled by their hash
+pub const EXAMPLE_LABEL: &str = "example";
+
+// a run slower than the baseline by more than this fraction is a regression
+pub const DEFAULT_THRESHOLD: f64 = 0.2;
+
+// differences smaller than this are timer noise, however large they are as a fraction
+pub const NOISE_FLOOR: Duration = Duration::from_micros(100);
+
+const REPORT_HEADER: &str = "day,part,input,iterations,parse_ns,compute_ns";
+
+// how many times to run each puzzle, stopping early once a puzzle has used up the time budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    pub iterations: u32,
+    pub time_budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            iterations: 10,
+            time_budget: Duration::from_secs(2),
+        }
+    }
+}
+
+// the median parse and compute times of one puzzle against one input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchResult {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub iterations: u32,
+    pub timing: Timing,
+}
+
+impl BenchResult {
+    fn matches(&self, other: &Self) -> bool {
+        self.day == other.day && self.part == other.part && self.input == other.input
+    }
+}
+
+// time a puzzle against an input, always running it at least once
+pub fn bench(
+    day: u8,
+    part: u8,
+    input: &str,
+    raw_input: &str,
+    config: &BenchConfig,
+) -> Result<BenchResult> {
+    let start = Instant::now();
+    let mut timings = Vec::new();
+
+    while timings.is_empty()
+        || (timings.len() < config.iterations as usize && start.elapsed() < config.time_budget)
+    {
+        let (_, timing) = solve_timed(day, part, raw_input)?;
+        timings.push(timing);
+    }
+
+    Ok(BenchResult {
+        day,
+        part,
+        input: input.to_string(),
+        iterations: timings.len() as u32,
+        timing: Timing {
+            parse: median(timings.iter().map(|timing| timing.parse).collect()),
+            compute: median(timings.iter().map(|timing| timing.compute).collect()),
+        },
+    })
+}
+
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort_unstable();
+    durations
+        .get(durations.len() / 2)
+        .copied()
+        .unwrap_or_default()
+}
+
+// Every bench result, read from and written to a CSV file:
+//
+//      day,part,input,iterations,parse_ns,compute_ns
+//      15,2,example,10,5210,1893022
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BenchReport {
+    pub results: Vec<BenchResult>,
+}
+
+impl BenchReport {
+    pub fn parse(raw: &str) -> Result<Self> {
+        let mut results = Vec::new();
+
+        for (line_number, line) in numbered_lines(raw) {
+            if line.is_empty() || line == REPORT_HEADER {
+                continue;
+            }
+            results.push(parse_result(line).map_err(|err| err.at_line(line_number))?);
+        }
+
+        Ok(Self { results })
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let raw = fs::read_to_string(path).map_err(|err| {
+            io::Error::new(
+                err.kind(),
+                format!("unable to read {}: {}", path.display(), err),
+            )
+        })?;
+        Self::parse(&raw).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), err),
+            )
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    // every result that got slower than its baseline by more than the threshold
+    pub fn regressions(&self, baseline: &BenchReport, threshold: f64) -> Vec<Regression> {
+        self.results
+            .iter()
+            .filter_map(|current| {
+                let before = baseline
+                    .results
+                    .iter()
+                    .find(|before| before.matches(current))?;
+                let (before_total, current_total) = (before.timing.total(), current.timing.total());
+
+                let slower_by = current_total.checked_sub(before_total)?;
+                let limit = before_total.as_secs_f64() * (1.0 + threshold);
+                (slower_by >= NOISE_FLOOR && current_total.as_secs_f64() > limit).then(|| {
+                    Regression {
+                        day: current.day,
+                        part: current.part,
+                        input: current.input.clone(),
+                        baseline: before_total,
+                        current: current_total,
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", REPORT_HEADER)?;
+        for result in &self.results {
+            writeln!(
+                f,
+                "{},{},{},{},{},{}",
+                result.day,
+                result.part,
+                result.input,
+                result.iterations,
+                result.timing.parse.as_nanos(),
+                result.timing.compute.as_nanos()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// 15,2,example,10,5210,1893022
+fn parse_result(line: &str) -> Result<BenchResult> {
+    let fields = line.split(',').collect::<Vec<&str>>();
+    let [day, part, input, iterations, parse_ns, compute_ns] = fields[..] else {
+        return Err(AocError::parse(
+            "six comma separated fields",
+            format!("{} fields", fields.len()),
+        ));
+    };
+
+    // where each field starts, for pointing at the one that's wrong
+    let column = |field: usize| fields[..field].iter().map(|f| f.len() + 1).sum::<usize>() + 1;
+    let nanos = |value: &str, field: usize| -> Result<Duration> {
+        parse_number(value, "a time in nanoseconds")
+            .map(Duration::from_nanos)
+            .map_err(|err| err.at_column(column(field)))
+    };
+
+    Ok(BenchResult {
+        day: parse_number(day, "a day number")?,
+        part: parse_number(part, "a part number").map_err(|err| err.at_column(column(1)))?,
+        input: input.to_string(),
+        iterations: parse_number(iterations, "a number of iterations")
+            .map_err(|err| err.at_column(column(3)))?,
+        timing: Timing {
+            parse: nanos(parse_ns, 4)?,
+            compute: nanos(compute_ns, 5)?,
+        },
+    })
+}
+
+// a puzzle that takes longer than it did in the baseline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regression {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {} part {} ({}) took {:?}, up from {:?} ({:+.0}%)",
+            self.day,
+            self.part,
+            self.input,
+            self.current,
+            self.baseline,
+            (self.current.as_secs_f64() / self.baseline.as_secs_f64().max(f64::EPSILON) - 1.0)
+                * 100.0
+        )
+    }
+}
+
+// a table of results for reading rather than parsing
+pub fn format_table(results: &[BenchResult]) -> String {
+    let mut table = vec![format!(
+        "{:<4} {:<5} {:<17} {:>5} {:>12} {:>12} {:>12}",
+        "day", "part", "input", "runs", "parse", "compute", "total"
+    )];
+    for result in results {
+        table.push(format!(
+            "{:<4} {:<5} {:<17} {:>5} {:>12} {:>12} {:>12}",
+            result.day,
+            result.part,
+            result.input,
+            result.iterations,
+            format!("{:.2?}", result.timing.parse),
+            format!("{:.2?}", result.timing.compute),
+            format!("{:.2?}", result.timing.total()),
+        ));
+    }
+    table.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example_input;
+
+    fn result(day: u8, input: &str, parse_us: u64, compute_us: u64) -> BenchResult {
+        BenchResult {
+            day,
+            part: 1,
+            input: input.to_string(),
+            iterations: 5,
+            timing: Timing {
+                parse: Duration::from_micros(parse_us),
+                compute: Duration::from_micros(compute_us),
+            },
+        }
+    }
+
+    #[test]
+    fn bench_example() -> Result<()> {
+        let config = BenchConfig {
+            iterations: 3,
+            time_budget: Duration::from_secs(1),
+        };
+        let result = bench(
+            1,
+            2,
+            EXAMPLE_LABEL,
+            example_input(1, 2).unwrap_or_default(),
+            &config,
+        )?;
+
+        assert_eq!((result.day, result.part), (1, 2));
+        assert!(result.iterations >= 1 && result.iterations <= 3);
+        assert!(bench(1, 3, EXAMPLE_LABEL, "199", &config).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn medians() {
+        let durations = [5, 1, 9, 3].map(Duration::from_nanos).to_vec();
+
+        assert_eq!(median(durations), Duration::from_nanos(5));
+        assert_eq!(median(vec![]), Duration::ZERO);
+    }
+
+    #[test]
+    fn report_round_trip() -> Result<()> {
+        let report = BenchReport {
+            results: vec![
+                result(15, EXAMPLE_LABEL, 5, 1893),
+                result(20, "1da9f311d171153a", 40, 2),
+            ],
+        };
+
+        let raw = report.to_string();
+        assert!(raw.starts_with(
+            "day,part,input,iterations,parse_ns,compute_ns\n15,1,example,5,5000,1893000\n"
+        ));
+        assert_eq!(BenchReport::parse(&raw)?, report);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_reports() {
+        assert_eq!(
+            BenchReport::parse("day,part,input,iterations,parse_ns,compute_ns\n1,2,example,3,4")
+                .err(),
+            Some(AocError::parse("six comma separated fields", "5 fields").at_line(2))
+        );
+        assert_eq!(
+            BenchReport::parse("1,2,example,3,4,5x").err(),
+            Some(
+                AocError::parse("a time in nanoseconds", "5x")
+                    .at_line(1)
+                    .at_column(17)
+            )
+        );
+    }
+
+    #[test]
+    fn find_regressions() {
+        let baseline = BenchReport {
+            results: vec![
+                result(9, EXAMPLE_LABEL, 100, 900),
+                result(15, EXAMPLE_LABEL, 10, 10),
+                result(22, EXAMPLE_LABEL, 100, 900),
+            ],
+        };
+        let current = BenchReport {
+            results: vec![
+                // 50% slower
+                result(9, EXAMPLE_LABEL, 100, 1400),
+                // 5x slower, but only by 40µs
+                result(15, EXAMPLE_LABEL, 10, 50),
+                // 10% slower
+                result(22, EXAMPLE_LABEL, 100, 1000),
+                // not in the baseline
+                result(20, EXAMPLE_LABEL, 100, 100_000),
+            ],
+        };
+
+        let regressions = current.regressions(&baseline, DEFAULT_THRESHOLD);
+        assert_eq!(
+            regressions,
+            vec![Regression {
+                day: 9,
+                part: 1,
+                input: String::from(EXAMPLE_LABEL),
+                baseline: Duration::from_micros(1000),
+                current: Duration::from_micros(1500),
+            }]
+        );
+        assert_eq!(
+            regressions[0].to_string(),
+            "day 9 part 1 (example) took 1.5ms, up from 1ms (+50%)"
+        );
+        assert!(current.regressions(&baseline, 0.05).len() == 2);
+    }
+}
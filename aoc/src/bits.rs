@@ -0,0 +1,812 @@
+use std::fmt::Display;
+
+use crate::error::{numbered_lines, AocError};
+use crate::solution::Result;
+
+// the type id that marks a literal value, every other type is an operator
+pub const LITERAL_TYPE_ID: u8 = 4;
+
+const VERSION_BITS: usize = 3;
+const TYPE_ID_BITS: usize = 3;
+// literal values are written 4 bits at a time, each group after a bit saying if there's more
+const LITERAL_GROUP_BITS: usize = 4;
+const SUBPACKET_BITS_BITS: usize = 15;
+const SUBPACKET_COUNT_BITS: usize = 11;
+
+// A packet from a BITS transmission
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Packet {
+    LiteralValue(LiteralValuePacket),
+    Operator(OperatorPacket),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiteralValuePacket {
+    pub version: u8,
+    pub value: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorPacket {
+    pub version: u8,
+    pub operator: Operator,
+    pub length_type: LengthType,
+    pub subpackets: Vec<Packet>,
+}
+
+// what an operator packet does with the values of its subpackets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LessThan,
+    EqualTo,
+}
+
+// how an operator packet says where its subpackets end
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthType {
+    // the total number of bits in the subpackets
+    Bits,
+    // the number of subpackets
+    Packets,
+}
+
+impl Operator {
+    pub fn from_type_id(type_id: u8) -> Option<Self> {
+        Some(match type_id {
+            0 => Operator::Sum,
+            1 => Operator::Product,
+            2 => Operator::Minimum,
+            3 => Operator::Maximum,
+            5 => Operator::GreaterThan,
+            6 => Operator::LessThan,
+            7 => Operator::EqualTo,
+            _ => return None,
+        })
+    }
+
+    pub fn type_id(&self) -> u8 {
+        match self {
+            Operator::Sum => 0,
+            Operator::Product => 1,
+            Operator::Minimum => 2,
+            Operator::Maximum => 3,
+            Operator::GreaterThan => 5,
+            Operator::LessThan => 6,
+            Operator::EqualTo => 7,
+        }
+    }
+
+    // the name used when printing a packet as an expression
+    pub fn name(&self) -> &'static str {
+        match self {
+            Operator::Sum => "sum",
+            Operator::Product => "product",
+            Operator::Minimum => "min",
+            Operator::Maximum => "max",
+            Operator::GreaterThan => "greater",
+            Operator::LessThan => "less",
+            Operator::EqualTo => "equal",
+        }
+    }
+
+    // comparisons work on exactly two values, everything else needs at least one
+    fn takes(&self, subpackets: usize) -> bool {
+        match self {
+            Operator::GreaterThan | Operator::LessThan | Operator::EqualTo => subpackets == 2,
+            _ => subpackets >= 1,
+        }
+    }
+
+    fn apply(&self, values: &[u64]) -> Result<u64> {
+        let too_big = || {
+            AocError::unsolvable(format!(
+                "the {} of {:?} doesn't fit in 64 bits",
+                self.name(),
+                values
+            ))
+        };
+        Ok(match self {
+            Operator::Sum => values
+                .iter()
+                .try_fold(0u64, |total, value| total.checked_add(*value))
+                .ok_or_else(too_big)?,
+            Operator::Product => values
+                .iter()
+                .try_fold(1u64, |total, value| total.checked_mul(*value))
+                .ok_or_else(too_big)?,
+            Operator::Minimum => values.iter().copied().min().unwrap_or_default(),
+            Operator::Maximum => values.iter().copied().max().unwrap_or_default(),
+            Operator::GreaterThan | Operator::LessThan | Operator::EqualTo => {
+                let (first, second) = (values.first(), values.get(1));
+                let holds = match self {
+                    Operator::GreaterThan => first > second,
+                    Operator::LessThan => first < second,
+                    _ => first == second,
+                };
+                holds as u64
+            }
+        })
+    }
+}
+
+impl Packet {
+    pub fn literal(version: u8, value: u64) -> Self {
+        Packet::LiteralValue(LiteralValuePacket { version, value })
+    }
+
+    pub fn operator(
+        version: u8,
+        operator: Operator,
+        length_type: LengthType,
+        subpackets: Vec<Packet>,
+    ) -> Self {
+        Packet::Operator(OperatorPacket {
+            version,
+            operator,
+            length_type,
+            subpackets,
+        })
+    }
+
+    pub fn version(&self) -> u8 {
+        match self {
+            Packet::LiteralValue(packet) => packet.version,
+            Packet::Operator(packet) => packet.version,
+        }
+    }
+
+    pub fn type_id(&self) -> u8 {
+        match self {
+            Packet::LiteralValue(_) => LITERAL_TYPE_ID,
+            Packet::Operator(packet) => packet.operator.type_id(),
+        }
+    }
+
+    // the versions of this packet and every packet inside it
+    pub fn version_sum(&self) -> u64 {
+        match self {
+            Packet::LiteralValue(packet) => packet.version as u64,
+            Packet::Operator(packet) => {
+                packet.version as u64
+                    + packet
+                        .subpackets
+                        .iter()
+                        .map(Packet::version_sum)
+                        .sum::<u64>()
+            }
+        }
+    }
+
+    // what the expression the packet stands for works out to
+    pub fn value(&self) -> Result<u64> {
+        match self {
+            Packet::LiteralValue(packet) => Ok(packet.value),
+            Packet::Operator(packet) => packet.operator.apply(
+                &packet
+                    .subpackets
+                    .iter()
+                    .map(Packet::value)
+                    .collect::<Result<Vec<u64>>>()?,
+            ),
+        }
+    }
+
+    // The packet as hex, padded with zeros to a whole number of digits
+    pub fn encode(&self) -> Result<String> {
+        let mut bits = BitWriter::new();
+        self.write_bits(&mut bits)?;
+        Ok(bits.to_hex())
+    }
+
+    fn write_bits(&self, bits: &mut BitWriter) -> Result<()> {
+        write_field(bits, self.version() as u64, VERSION_BITS, "a version")?;
+        write_field(bits, self.type_id() as u64, TYPE_ID_BITS, "a type id")?;
+
+        match self {
+            Packet::LiteralValue(packet) => {
+                let groups = (u64::BITS - packet.value.leading_zeros())
+                    .div_ceil(LITERAL_GROUP_BITS as u32)
+                    .max(1) as usize;
+                for group in (0..groups).rev() {
+                    bits.write((group > 0) as u64, 1);
+                    let shift = group * LITERAL_GROUP_BITS;
+                    bits.write(packet.value >> shift, LITERAL_GROUP_BITS);
+                }
+            }
+            Packet::Operator(packet) => {
+                if !packet.operator.takes(packet.subpackets.len()) {
+                    return Err(wrong_subpackets(
+                        packet.operator.type_id(),
+                        packet.subpackets.len(),
+                    ));
+                }
+
+                let mut subpacket_bits = BitWriter::new();
+                for subpacket in &packet.subpackets {
+                    subpacket.write_bits(&mut subpacket_bits)?;
+                }
+                match packet.length_type {
+                    LengthType::Bits => {
+                        bits.write(0, 1);
+                        write_field(
+                            bits,
+                            subpacket_bits.len() as u64,
+                            SUBPACKET_BITS_BITS,
+                            "a subpacket length",
+                        )?;
+                    }
+                    LengthType::Packets => {
+                        bits.write(1, 1);
+                        write_field(
+                            bits,
+                            packet.subpackets.len() as u64,
+                            SUBPACKET_COUNT_BITS,
+                            "a subpacket count",
+                        )?;
+                    }
+                }
+                bits.append(&subpacket_bits);
+            }
+        }
+        Ok(())
+    }
+}
+
+// A packet as the expression it stands for, like sum(3, max(7, 9))
+impl Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Packet::LiteralValue(packet) => write!(f, "{}", packet.value),
+            Packet::Operator(packet) => {
+                write!(f, "{}(", packet.operator.name())?;
+                for (i, subpacket) in packet.subpackets.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", subpacket)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+// a field of a packet, which has to fit in its bits
+fn write_field(bits: &mut BitWriter, value: u64, length: usize, what: &str) -> Result<()> {
+    if value >> length != 0 {
+        return Err(AocError::unsolvable(format!(
+            "{} of {} doesn't fit in {} bits",
+            what, value, length
+        )));
+    }
+    bits.write(value, length);
+    Ok(())
+}
+
+// Each pair of hex digits as a byte, an odd last digit goes in the top half of the last byte
+pub fn hex_to_bytes(hex: &str) -> Result<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(hex.len().div_ceil(2));
+    for (column, digit) in hex.chars().enumerate() {
+        let value = digit.to_digit(16).ok_or_else(|| {
+            AocError::parse("a hexadecimal digit", digit.to_string())
+                .at_line(1)
+                .at_column(column + 1)
+        })? as u8;
+        if column % 2 == 0 {
+            bytes.push(value << 4);
+        } else if let Some(byte) = bytes.last_mut() {
+            *byte |= value;
+        }
+    }
+    Ok(bytes)
+}
+
+// A transmission is a single packet, then zeros out to the end of the hex
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transmission {
+    pub packet: Packet,
+    // how many zero bits follow the packet
+    pub padding: usize,
+}
+
+// The packet in a hex transmission, anything after it has to be padding
+pub fn decode(hex: &str) -> Result<Transmission> {
+    let bytes = hex_to_bytes(hex)?;
+    let mut reader = BitReader::new(&bytes, hex.chars().count() * 4);
+
+    let packet = read_packet(&mut reader)?;
+    let padding = reader.len() - reader.position();
+    while !reader.is_empty() {
+        if reader.read(1)? == 1 {
+            return Err(AocError::malformed(format!(
+                "the padding after the packet has a bit set at bit {}",
+                reader.position() - 1
+            )));
+        }
+    }
+    Ok(Transmission { packet, padding })
+}
+
+// One transmission per line, blank lines are skipped
+pub fn decode_stream(input: &str) -> Result<Vec<Transmission>> {
+    numbered_lines(input)
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line_number, line)| decode(line).map_err(|err| err.at_line(line_number)))
+        .collect()
+}
+
+// Reads bits packed eight to a byte, most significant first, keeping track of where it's got to
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    // in bits, which can stop part way through the last byte
+    length: usize,
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(bytes: &'a [u8], length: usize) -> Self {
+        Self {
+            bytes,
+            length: length.min(bytes.len() * 8),
+            position: 0,
+        }
+    }
+
+    // how many bits have been read
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.position >= self.length
+    }
+
+    // moves past bits without reading them, stopping at the end
+    pub fn skip(&mut self, length: usize) {
+        self.position = (self.position + length).min(self.length);
+    }
+
+    // the next length bits as a number, most significant first, a whole byte at a time where it can
+    pub fn read(&mut self, length: usize) -> Result<u64> {
+        assert!(length <= 64, "can't read {} bits into a u64", length);
+        if self.position + length > self.length {
+            return Err(AocError::malformed(format!(
+                "reading {} bits at bit {} goes past the end of the {} bit transmission",
+                length, self.position, self.length
+            )));
+        }
+
+        let mut value = 0;
+        let mut left = length;
+        while left > 0 {
+            let offset = self.position % 8;
+            let take = left.min(8 - offset);
+            let byte = self.bytes[self.position / 8] as u64;
+            let bits = byte >> (8 - offset - take) & ((1 << take) - 1);
+
+            value = value << take | bits;
+            self.position += take;
+            left -= take;
+        }
+        Ok(value)
+    }
+}
+
+// Bits packed eight to a byte, most significant first
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    length: usize,
+}
+
+impl BitWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    // the lowest length bits of value
+    pub fn write(&mut self, value: u64, length: usize) {
+        for bit in (0..length).rev() {
+            if self.length.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            if value >> bit & 1 == 1 {
+                self.bytes[self.length / 8] |= 0x80 >> (self.length % 8);
+            }
+            self.length += 1;
+        }
+    }
+
+    pub fn append(&mut self, other: &BitWriter) {
+        let mut reader = BitReader::new(&other.bytes, other.length);
+        while !reader.is_empty() {
+            let length = (reader.len() - reader.position()).min(64);
+            // there are always length bits left to read
+            let value = reader.read(length).unwrap_or_default();
+            self.write(value, length);
+        }
+    }
+
+    // the bits as hex, padded with zeros to a whole number of digits
+    pub fn to_hex(&self) -> String {
+        let digits = self.length.div_ceil(4);
+        self.bytes
+            .iter()
+            .flat_map(|byte| [byte >> 4, byte & 0xF])
+            .take(digits)
+            .map(|digit| format!("{:X}", digit))
+            .collect()
+    }
+}
+
+// reads a packet, and everything in it, from wherever the reader has got to
+pub fn read_packet(reader: &mut BitReader) -> Result<Packet> {
+    let start = reader.position();
+    let version = reader.read(VERSION_BITS)? as u8;
+    let type_id = reader.read(TYPE_ID_BITS)? as u8;
+
+    let operator = match Operator::from_type_id(type_id) {
+        Some(operator) => operator,
+        None => {
+            let mut value: u64 = 0;
+            let mut is_more = true;
+            while is_more {
+                is_more = reader.read(1)? == 1;
+                if value >> (u64::BITS as usize - LITERAL_GROUP_BITS) != 0 {
+                    return Err(AocError::unsolvable(format!(
+                        "the literal value in the packet at bit {} doesn't fit in 64 bits",
+                        start
+                    )));
+                }
+                value = value << LITERAL_GROUP_BITS | reader.read(LITERAL_GROUP_BITS)?;
+            }
+            return Ok(Packet::literal(version, value));
+        }
+    };
+
+    let mut subpackets = Vec::new();
+    let length_type = if reader.read(1)? == 0 {
+        let length = reader.read(SUBPACKET_BITS_BITS)? as usize;
+        let subpackets_start = reader.position();
+        while reader.position() < subpackets_start + length {
+            subpackets.push(read_packet(reader)?);
+        }
+        if reader.position() != subpackets_start + length {
+            return Err(AocError::malformed(format!(
+                "the operator packet at bit {} should hold {} bits of subpackets but holds {}",
+                start,
+                length,
+                reader.position() - subpackets_start
+            )));
+        }
+        LengthType::Bits
+    } else {
+        let count = reader.read(SUBPACKET_COUNT_BITS)?;
+        for _ in 0..count {
+            subpackets.push(read_packet(reader)?);
+        }
+        LengthType::Packets
+    };
+
+    if !operator.takes(subpackets.len()) {
+        return Err(AocError::malformed(format!(
+            "the operator packet at bit {} is of type {}, which can't have {} subpackets",
+            start,
+            type_id,
+            subpackets.len()
+        )));
+    }
+    Ok(Packet::operator(version, operator, length_type, subpackets))
+}
+
+fn wrong_subpackets(type_id: u8, subpackets: usize) -> AocError {
+    AocError::malformed(format!(
+        "an operator packet of type {} can't have {} subpackets",
+        type_id, subpackets
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_values() -> Result<()> {
+        let test_data = [0b0011_1010, 0b1100_0000];
+        assert_eq!(BitReader::new(&test_data, 5).read(3)?, 1);
+        assert_eq!(BitReader::new(&test_data, 5).read(4)?, 3);
+
+        let mut reader = BitReader::new(&test_data, 10);
+        reader.skip(2);
+        assert_eq!(reader.read(3)?, 7);
+        // across the byte boundary
+        assert_eq!(reader.read(5)?, 0b01011);
+        assert!(reader.is_empty());
+        assert_eq!(
+            reader.read(1).err(),
+            Some(AocError::malformed(
+                "reading 1 bits at bit 10 goes past the end of the 10 bit transmission"
+            ))
+        );
+        assert!(BitReader::new(&[0], 2).read(6).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn write_values() {
+        let mut bits = BitWriter::new();
+        bits.write(0b110, 3);
+        bits.write(0b1_0111_1110, 9);
+        assert_eq!(
+            (bits.len(), bits.bytes()),
+            (12, &[0b1101_0111, 0b1110_0000][..])
+        );
+        assert_eq!(bits.to_hex(), "D7E");
+
+        let mut appended = BitWriter::new();
+        appended.write(1, 1);
+        appended.append(&bits);
+        assert_eq!(appended.to_hex(), "EBF0");
+        assert_eq!(hex_to_bytes("d7e").ok(), Some(vec![0xD7, 0xE0]));
+    }
+
+    #[test]
+    fn decode_literal() -> Result<()> {
+        assert_eq!(decode("D2FE28")?.packet, Packet::literal(6, 2021));
+        assert_eq!(decode("d2fe28")?, decode("D2FE28")?);
+        Ok(())
+    }
+
+    #[test]
+    fn decode_operators() -> Result<()> {
+        assert_eq!(
+            decode("38006F45291200")?,
+            Transmission {
+                packet: Packet::operator(
+                    1,
+                    Operator::LessThan,
+                    LengthType::Bits,
+                    vec![Packet::literal(6, 10), Packet::literal(2, 20)]
+                ),
+                padding: 7
+            }
+        );
+        assert_eq!(
+            decode("EE00D40C823060")?.packet,
+            Packet::operator(
+                7,
+                Operator::Maximum,
+                LengthType::Packets,
+                vec![
+                    Packet::literal(2, 1),
+                    Packet::literal(4, 2),
+                    Packet::literal(1, 3)
+                ]
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> Result<()> {
+        let operators = [
+            Operator::Sum,
+            Operator::Product,
+            Operator::Minimum,
+            Operator::Maximum,
+            Operator::GreaterThan,
+            Operator::LessThan,
+            Operator::EqualTo,
+        ];
+        for (i, operator) in operators.into_iter().enumerate() {
+            for length_type in [LengthType::Bits, LengthType::Packets] {
+                let nested = Packet::operator(
+                    3,
+                    Operator::Sum,
+                    LengthType::Packets,
+                    vec![Packet::literal(0, 0), Packet::literal(7, 1 << 40)],
+                );
+                let packet = Packet::operator(
+                    i as u8,
+                    operator,
+                    length_type,
+                    vec![Packet::literal(5, 15 + i as u64), nested],
+                );
+
+                let hex = packet.encode()?;
+                assert_eq!(decode(&hex)?.packet, packet);
+                assert_eq!(Operator::from_type_id(operator.type_id()), Some(operator));
+            }
+        }
+
+        for hex in [
+            "C200B40A82",
+            "9C0141080250320F1802104A08",
+            "A0016C880162017C3686B18A3D4780",
+        ] {
+            let packet = decode(hex)?.packet;
+            assert_eq!(decode(&packet.encode()?)?.packet, packet);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn padding() -> Result<()> {
+        // a top level operator that ends exactly on a hex digit has no padding at all
+        let packet = Packet::operator(
+            2,
+            Operator::Product,
+            LengthType::Packets,
+            vec![Packet::literal(1, 6), Packet::literal(3, 7)],
+        );
+        let hex = packet.encode()?;
+        assert_eq!(hex.len() * 4, 40);
+        assert_eq!(decode(&hex)?, Transmission { packet, padding: 0 });
+
+        // however many zeros follow
+        assert_eq!(decode("D2FE28000000")?.padding, 27);
+        assert_eq!(
+            decode("D2FE29").err(),
+            Some(AocError::malformed(
+                "the padding after the packet has a bit set at bit 23"
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn streams() -> Result<()> {
+        let transmissions = decode_stream("D2FE28\n\n38006F45291200\n")?;
+        assert_eq!(
+            transmissions
+                .iter()
+                .map(|transmission| transmission.packet.value())
+                .collect::<Result<Vec<_>>>()?,
+            vec![2021, 1]
+        );
+        assert_eq!(
+            decode_stream("D2FE28\n38006X45291200").err(),
+            Some(
+                AocError::parse("a hexadecimal digit", "X")
+                    .at_line(2)
+                    .at_column(6)
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn long_literals() -> Result<()> {
+        let packet = Packet::literal(1, u64::MAX);
+        assert_eq!(decode(&packet.encode()?)?.packet, packet);
+
+        // 17 groups of 4 bits is 68 bits, too many for a u64, in a sum starting at bit 0
+        let mut bits = BitWriter::new();
+        bits.write(0, 3);
+        bits.write(0, 3);
+        bits.write(1, 1);
+        bits.write(1, SUBPACKET_COUNT_BITS);
+        bits.write(2, VERSION_BITS);
+        bits.write(LITERAL_TYPE_ID as u64, TYPE_ID_BITS);
+        for group in (0..17).rev() {
+            bits.write((group > 0) as u64, 1);
+            bits.write(0xF, LITERAL_GROUP_BITS);
+        }
+        assert_eq!(
+            decode(&bits.to_hex()).err(),
+            Some(AocError::unsolvable(
+                "the literal value in the packet at bit 18 doesn't fit in 64 bits"
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn overflowing_values() -> Result<()> {
+        let big = 1 << 60;
+        let product = Packet::operator(
+            0,
+            Operator::Product,
+            LengthType::Packets,
+            vec![Packet::literal(0, big), Packet::literal(0, big)],
+        );
+        assert_eq!(
+            decode(&product.encode()?)?.packet.value().err(),
+            Some(AocError::unsolvable(format!(
+                "the product of [{}, {}] doesn't fit in 64 bits",
+                big, big
+            )))
+        );
+
+        let sum = Packet::operator(
+            0,
+            Operator::Sum,
+            LengthType::Bits,
+            vec![Packet::literal(0, u64::MAX), Packet::literal(0, 1)],
+        );
+        assert!(matches!(sum.value(), Err(AocError::Unsolvable(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn pretty_print() -> Result<()> {
+        let packet = Packet::operator(
+            0,
+            Operator::Sum,
+            LengthType::Bits,
+            vec![
+                Packet::literal(0, 3),
+                Packet::operator(
+                    0,
+                    Operator::Maximum,
+                    LengthType::Packets,
+                    vec![Packet::literal(0, 7), Packet::literal(0, 9)],
+                ),
+            ],
+        );
+        assert_eq!(packet.to_string(), "sum(3, max(7, 9))");
+        assert_eq!(packet.value()?, 12);
+        assert_eq!(
+            decode("9C0141080250320F1802104A08")?.packet.to_string(),
+            "equal(sum(1, 3), product(2, 2))"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn bad_packets() {
+        assert_eq!(
+            hex_to_bytes("8A0G4A").err(),
+            Some(
+                AocError::parse("a hexadecimal digit", "G")
+                    .at_line(1)
+                    .at_column(4)
+            ),
+        );
+        assert!(matches!(decode("8A004A"), Err(AocError::MalformedInput(_))));
+        assert_eq!(
+            decode("38006F45291200")
+                .ok()
+                .and_then(|transmission| transmission.packet.encode().ok()),
+            Some(String::from("38006F4529120"))
+        );
+        // the length in an operator packet that doesn't match its subpackets
+        assert_eq!(
+            decode("38006745291200").err(),
+            Some(AocError::malformed(
+                "the operator packet at bit 0 should hold 25 bits of subpackets but holds 27"
+            ))
+        );
+
+        let comparison = Packet::operator(
+            0,
+            Operator::EqualTo,
+            LengthType::Bits,
+            vec![Packet::literal(0, 1)],
+        );
+        assert!(comparison.encode().is_err());
+        assert!(Packet::literal(8, 1).encode().is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use crate::error::{numbered_lines, parse_number, AocError};
+use crate::solution::{Answer, Result, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<i32>> {
+    numbered_lines(input)
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line_number, line)| {
+            parse_number(line, "a depth").map_err(|err: AocError| err.at_line(line_number))
+        })
+        .collect()
+}
+
+pub fn part1(depths: &[i32]) -> u32 {
+    count_increases(depths)
+}
+
+pub fn part2(depths: &[i32]) -> u32 {
+    if depths.len() < 3 {
+        return 0;
+    }
+
+    // need to map our depths to depth windows and then run through this same algorithm
+    let depth_window_sums: Vec<i32> = depths
+        .iter()
+        .enumerate()
+        .skip(2)
+        .map(|(i, depth)| depth + depths[i - 1] + depths[i - 2])
+        .collect();
+
+    count_increases(&depth_window_sums)
+}
+
+fn count_increases(depths: &[i32]) -> u32 {
+    let mut count_of_increases = 0;
+    for (i, depth) in depths.iter().enumerate().skip(1) {
+        let prev_depth = depths[i - 1];
+
+        if prev_depth < *depth {
+            count_of_increases += 1;
+        }
+    }
+    count_of_increases
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn part1_example1() -> Result<()> {
+        assert_eq!(
+            part1(&parse(
+                "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n"
+            )?),
+            7
+        );
+        Ok(())
+    }
+    #[test]
+    fn part1_basic_test() -> Result<()> {
+        assert_eq!(part1(&parse("199\n200\n")?), 1);
+        Ok(())
+    }
+    #[test]
+    fn part1_no_input() -> Result<()> {
+        assert_eq!(part1(&parse("")?), 0);
+        Ok(())
+    }
+    #[test]
+    fn part2_example1() -> Result<()> {
+        assert_eq!(
+            part2(&parse(
+                "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n"
+            )?),
+            5
+        );
+        Ok(())
+    }
+    #[test]
+    fn part2_basic_test() -> Result<()> {
+        assert_eq!(part2(&parse("1\n0\n0\n2\n")?), 1);
+        Ok(())
+    }
+    #[test]
+    fn parse_bad_depth() {
+        assert_eq!(
+            parse("199\n2o0\n").err(),
+            Some(AocError::parse("a depth", "2o0").at_line(2))
+        );
+    }
+    #[test]
+    fn part2_no_input() -> Result<()> {
+        assert_eq!(part2(&parse("")?), 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,208 @@
+use crate::error::{numbered_lines, AocError};
+use crate::solution::{Answer, Result, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<String>> {
+    let mut lines = Vec::new();
+    for (line_number, line) in numbered_lines(input) {
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(column) = line.find(|c: char| !"()[]{}<>".contains(c)) {
+            return Err(AocError::parse("a bracket", &line[column..=column])
+                .at_line(line_number)
+                .at_column(column + 1));
+        }
+        lines.push(line.to_string());
+    }
+    Ok(lines)
+}
+
+pub fn part1(lines: &[String]) -> u32 {
+    // for corrupt chunks they have to close with the wrong character
+    // so we need some form of recursive parsing to make sure the characters that close a chunk are
+    // valid
+
+    let mut score = 0;
+    for line in lines {
+        let (data, valid, _score) = parse_line(line);
+
+        if !valid {
+            match data.chars().next() {
+                Some(')') => score += 3,
+                Some(']') => score += 57,
+                Some('}') => score += 1197,
+                Some('>') => score += 25137,
+                _ => (),
+            };
+        }
+    }
+    score
+}
+
+pub fn part2(lines: &[String]) -> Result<u64> {
+    let mut valid_scores: Vec<u64> = Vec::new();
+    for line in lines {
+        let (_data, valid, score) = parse_line(line);
+
+        // complete lines have nothing to score
+        if valid && score > 0 {
+            valid_scores.push(score);
+        }
+    }
+    valid_scores.sort_unstable();
+
+    // find middle score
+    // middle index = len / 2
+    let middle_index = valid_scores.len() / 2;
+    let score = valid_scores
+        .get(middle_index)
+        .ok_or_else(|| AocError::unsolvable("no incomplete lines found"))?;
+    Ok(*score)
+}
+
+// a whole line is corrupt if a closing character is left over once its chunks are done
+fn parse_line(line: &str) -> (&str, bool, u64) {
+    match parse_chunk(line, 0) {
+        (data, true, score) if !data.is_empty() => (data, false, score),
+        result => result,
+    }
+}
+
+// returns the unparsed remainder, whether the line is still valid, and the completion score so far
+fn parse_chunk(data: &str, score: u64) -> (&str, bool, u64) {
+    // look at first character
+    //      if it's an open char then we need to pop it and recurse
+    //      if it's a close char we should return, something else will need to check it
+    //
+    //      with recursed data we need to check if it's an open char?
+    //
+    //
+    //
+    // (()())
+    //
+    // pop first, recurse
+    // pop second, recurse
+    // return third
+    // compare third with second, if valid, recurse?
+    //
+    // that means we do,
+    // pop, if open recurse
+    // if close return
+    //
+    // with recursed data, if valid, return recurse?
+    // if not valid return incorrect char, invalid
+
+    let mut chars = data.chars();
+
+    // if we're done then we're valid
+    let Some(first_char) = chars.next() else {
+        return ("", true, score);
+    };
+
+    if first_char == ')' || first_char == ']' || first_char == '>' || first_char == '}' {
+        return (data, true, score);
+    }
+
+    let (rem_data, valid, score) = parse_chunk(chars.as_str(), score);
+
+    if !valid {
+        return (rem_data, valid, score);
+    }
+
+    let mut rem_chars = rem_data.chars();
+
+    // unended line, still valid
+    // now we need to end the line validly
+    let Some(first_rem_char) = rem_chars.next() else {
+        return match first_char {
+            '(' => ("", true, score * 5 + 1),
+            '[' => ("", true, score * 5 + 2),
+            '{' => ("", true, score * 5 + 3),
+            '<' => ("", true, score * 5 + 4),
+            _ => ("", true, score),
+        };
+    };
+
+    if (first_char == '(' && first_rem_char == ')')
+        || (first_char == '[' && first_rem_char == ']')
+        || (first_char == '<' && first_rem_char == '>')
+        || (first_char == '{' && first_rem_char == '}')
+    {
+        return parse_chunk(rem_chars.as_str(), score);
+    }
+
+    (rem_data, false, score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> String {
+        let test_data: Vec<&str> = vec![
+            "[({(<(())[]>[[{[]{<()<>>",
+            "[(()[<>])]({[<{<<[]>>(",
+            "{([(<{}[<>[]}>{[]{[(<()>",
+            "(((({<>}<{<{<>}{[]{[]{}",
+            "[[<[([]))<([[{}[[()]]]",
+            "[{[{({}]{}}([{[{{{}}([]",
+            "{<[[]]>}<{[{[{[]{()[[[]",
+            "[<(<(<(<{}))><([]([]()",
+            "<{([([[(<>()){}]>(<<{{",
+            "<{([{{}}[<[[[<>{}]]]>[]]",
+        ];
+        test_data.join("\n")
+    }
+
+    #[test]
+    fn part1_example1() -> Result<()> {
+        assert_eq!(part1(&parse(&example())?), 26397);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example1() -> Result<()> {
+        assert_eq!(part2(&parse(&example())?)?, 288957);
+        Ok(())
+    }
+
+    #[test]
+    fn unexpected_closing_character() -> Result<()> {
+        assert_eq!(part1(&parse("())")?), 3);
+        assert_eq!(part1(&parse("]")?), 57);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_characters() {
+        assert_eq!(
+            parse("[()]\n(<x>)").err(),
+            Some(AocError::parse("a bracket", "x").at_line(2).at_column(3)),
+        );
+    }
+
+    #[test]
+    fn no_incomplete_lines() -> Result<()> {
+        assert!(matches!(part2(&parse("()")?), Err(AocError::Unsolvable(_))));
+        Ok(())
+    }
+}
@@ -0,0 +1,383 @@
+use std::fmt::Display;
+
+use crate::error::AocError;
+use crate::grid::{offset_within, Grid, SURROUNDING};
+use crate::log::trace;
+use crate::solution::{Answer, Result, Solution};
+
+pub const PART1_STEPS: u32 = 100;
+pub const PART2_MAX_STEPS: u32 = 1000;
+
+// an octopus flashes once its energy goes above this
+pub const FLASH_THRESHOLD: u32 = 9;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = OctopusMatrix;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input, PART1_STEPS).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input, PART2_MAX_STEPS)?.into())
+    }
+}
+
+// The energy of every octopus. Flashing works through a stack of octopi that have gone over the
+// threshold, which is kept between steps so stepping doesn't allocate once it's warmed up.
+#[derive(Debug, Clone)]
+pub struct OctopusMatrix {
+    energy: Grid<u32>,
+    threshold: u32,
+    flashing: Vec<(usize, usize)>,
+}
+
+impl OctopusMatrix {
+    pub fn new(energy: Grid<u32>) -> Self {
+        Self {
+            energy,
+            threshold: FLASH_THRESHOLD,
+            flashing: Vec::new(),
+        }
+    }
+
+    // a grid of random energy levels, the same for the same seed
+    pub fn generate(width: usize, height: usize, seed: u64) -> Self {
+        // xorshift, which gets stuck on 0
+        let mut state = seed.max(1);
+        let mut energy = Grid::filled(width, height, 0);
+        for value in energy.values_mut() {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            *value = (state % 10) as u32;
+        }
+        Self::new(energy)
+    }
+
+    pub fn with_threshold(mut self, threshold: u32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.energy.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.energy.is_empty()
+    }
+
+    // Every octopus gains one energy, then each one that goes over the threshold flashes and gives
+    // one to everything around it. An octopus is pushed onto the stack as it goes over, so it can
+    // only flash once. Anything that flashed starts the next step at 0. Returns the flash count.
+    pub fn next_step(&mut self) -> u32 {
+        let (width, height) = (self.energy.width(), self.energy.height());
+        let threshold = self.threshold;
+        let mut flash_count = 0;
+
+        // energies saturate, so a threshold of u32::MAX is never gone over
+        for (position, energy) in self.energy.iter_mut() {
+            *energy = energy.saturating_add(1);
+            // energies given above the threshold are already over it
+            if *energy > threshold {
+                self.flashing.push(position);
+            }
+        }
+
+        while let Some((x, y)) = self.flashing.pop() {
+            flash_count += 1;
+
+            for &(dx, dy) in &SURROUNDING {
+                let Some(neighbour) = offset_within(x, y, dx, dy, width, height) else {
+                    continue;
+                };
+                let energy = &mut self.energy[neighbour];
+                if *energy <= threshold {
+                    *energy = energy.saturating_add(1);
+                    if *energy > threshold {
+                        self.flashing.push(neighbour);
+                    }
+                }
+            }
+        }
+
+        for energy in self.energy.values_mut() {
+            if *energy > threshold {
+                *energy = 0;
+            }
+        }
+        flash_count
+    }
+}
+
+impl Display for OctopusMatrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.energy)
+    }
+}
+
+pub fn parse(input: &str) -> Result<OctopusMatrix> {
+    Ok(OctopusMatrix::new(Grid::parse_digits(
+        input,
+        "octopi",
+        "an energy level",
+    )?))
+}
+
+// total number of flashes over the given number of steps
+pub fn part1(oct_matrix: &OctopusMatrix, steps: u32) -> u32 {
+    let mut oct_matrix = oct_matrix.clone();
+
+    let mut flash_counter = 0;
+    for step_count in 1..=steps {
+        flash_counter += oct_matrix.next_step();
+        trace!("After step {}:\n{}", step_count, oct_matrix);
+    }
+    flash_counter
+}
+
+// first step where every octopus flashes at once, unsolvable if that doesn't happen within max_steps
+pub fn part2(oct_matrix: &OctopusMatrix, max_steps: u32) -> Result<u32> {
+    let mut oct_matrix = oct_matrix.clone();
+    let octopus_count = octopus_count(&oct_matrix)?;
+
+    for step_count in 1..=max_steps {
+        if oct_matrix.next_step() == octopus_count {
+            return Ok(step_count);
+        }
+        trace!("After step {}:\n{}", step_count, oct_matrix);
+    }
+    Err(AocError::unsolvable(format!(
+        "the octopi don't all flash together within {} steps",
+        max_steps
+    )))
+}
+
+// What happened over a run of steps
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    // the number of flashes in each step, starting from step 1
+    pub flashes: Vec<u32>,
+    // the first step where every octopus flashed at once
+    pub first_sync: Option<u32>,
+    // how many steps it takes for them all to flash together again after that
+    pub sync_period: Option<u32>,
+}
+
+impl Stats {
+    pub fn total_flashes(&self) -> u64 {
+        self.flashes.iter().map(|&flashes| u64::from(flashes)).sum()
+    }
+}
+
+// Step until the octopi have flashed together twice, or until max_steps, recording the flashes on
+// the way. Once they're in sync every octopus has the same energy, so they stay in sync and the
+// gap between the first two syncs repeats forever.
+pub fn simulate(oct_matrix: &OctopusMatrix, max_steps: u32) -> Result<Stats> {
+    let mut oct_matrix = oct_matrix.clone();
+    let octopus_count = octopus_count(&oct_matrix)?;
+    let mut stats = Stats {
+        flashes: Vec::new(),
+        first_sync: None,
+        sync_period: None,
+    };
+
+    for step_count in 1..=max_steps {
+        let flashes = oct_matrix.next_step();
+        stats.flashes.push(flashes);
+
+        if flashes == octopus_count {
+            match stats.first_sync {
+                None => stats.first_sync = Some(step_count),
+                Some(first_sync) => {
+                    stats.sync_period = Some(step_count - first_sync);
+                    break;
+                }
+            }
+        }
+    }
+    Ok(stats)
+}
+
+// the flashes in a step are counted in a u32, so there can't be more octopi than that holds
+fn octopus_count(oct_matrix: &OctopusMatrix) -> Result<u32> {
+    u32::try_from(oct_matrix.len()).map_err(|_| {
+        AocError::malformed(format!(
+            "there are {} octopi, too many to count the flashes of",
+            oct_matrix.len()
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> String {
+        let test_data: Vec<&str> = vec![
+            "5483143223",
+            "2745854711",
+            "5264556173",
+            "6141336146",
+            "6357385478",
+            "4167524645",
+            "2176841721",
+            "6882881134",
+            "4846848554",
+            "5283751526",
+        ];
+        test_data.join("\n")
+    }
+
+    #[test]
+    fn part1_example1() -> Result<()> {
+        assert_eq!(part1(&parse(&example())?, PART1_STEPS), 1656);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_step_1() -> Result<()> {
+        assert_eq!(part1(&parse(&example())?, 1), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_step_2() -> Result<()> {
+        assert_eq!(part1(&parse(&example())?, 2), 35);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_step_10() -> Result<()> {
+        assert_eq!(part1(&parse(&example())?, 10), 204);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_simple_flash() -> Result<()> {
+        assert_eq!(part1(&parse("98\n81")?, 1), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example1() -> Result<()> {
+        assert_eq!(part2(&parse(&example())?, PART2_MAX_STEPS)?, 195);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_no_sync() -> Result<()> {
+        assert!(matches!(
+            part2(&parse(&example())?, 10).err(),
+            Some(AocError::Unsolvable(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn rectangular_grid() -> Result<()> {
+        // the middle row flashes and sets off the rows either side
+        let oct_matrix = parse("111\n999\n111")?;
+        assert_eq!(part1(&oct_matrix, 1), 3);
+
+        let oct_matrix = parse("19\n11\n11")?;
+        assert_eq!(part1(&oct_matrix, 1), 1);
+        assert_eq!(oct_matrix.to_string(), "19\n11\n11\n");
+        Ok(())
+    }
+
+    #[test]
+    fn transposed_grid() -> Result<()> {
+        // a wide grid and the same grid on its side flash the same amount
+        let example = example();
+        let rows: Vec<&str> = example.lines().take(4).collect();
+        let columns = (0..10)
+            .map(|x| rows.iter().map(|row| &row[x..=x]).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let wide = parse(&rows.join("\n"))?;
+        let tall = parse(&columns)?;
+        assert_eq!(part1(&wide, PART1_STEPS), part1(&tall, PART1_STEPS));
+        assert_eq!(
+            part2(&wide, PART2_MAX_STEPS)?,
+            part2(&tall, PART2_MAX_STEPS)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn thresholds() -> Result<()> {
+        // nothing flashes in the first step until an octopus gets past 9
+        assert_eq!(part1(&parse("98\n81")?.with_threshold(10), 1), 0);
+        assert_eq!(part1(&parse("98\n81")?.with_threshold(4), 1), 4);
+
+        // no energy level can get past the largest threshold
+        let oct_matrix = OctopusMatrix::generate(10, 10, 7).with_threshold(u32::MAX);
+        assert_eq!(part1(&oct_matrix, 1), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn sync_stats() -> Result<()> {
+        let stats = simulate(&parse(&example())?, PART2_MAX_STEPS)?;
+
+        assert_eq!(stats.first_sync, Some(195));
+        assert_eq!(stats.sync_period, Some(FLASH_THRESHOLD + 1));
+        assert_eq!(stats.flashes.len(), 205);
+        assert_eq!(stats.flashes[..10].iter().sum::<u32>(), 204);
+
+        let stats = simulate(&parse(&example())?.with_threshold(4), PART2_MAX_STEPS)?;
+        assert_eq!(stats.sync_period, Some(5));
+
+        let stats = simulate(&parse(&example())?, 100)?;
+        assert_eq!(stats.total_flashes(), 1656);
+        assert_eq!((stats.first_sync, stats.sync_period), (None, None));
+        Ok(())
+    }
+
+    #[test]
+    fn generated_grids() -> Result<()> {
+        let oct_matrix = OctopusMatrix::generate(60, 40, 2021);
+        assert_eq!(oct_matrix.len(), 2400);
+        assert_eq!(
+            oct_matrix.to_string(),
+            OctopusMatrix::generate(60, 40, 2021).to_string()
+        );
+        assert_ne!(
+            oct_matrix.to_string(),
+            OctopusMatrix::generate(60, 40, 2022).to_string()
+        );
+
+        // not every grid syncs, but once one does it stays in sync
+        assert_eq!(simulate(&oct_matrix, 1000)?.first_sync, None);
+        let stats = simulate(&OctopusMatrix::generate(16, 12, 5), PART2_MAX_STEPS)?;
+        assert_eq!(stats.first_sync, Some(172));
+        assert_eq!(stats.sync_period, Some(FLASH_THRESHOLD + 1));
+        assert_eq!(stats.flashes.len(), 182);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_energy_levels() {
+        assert_eq!(
+            parse("98\n8*").err(),
+            Some(
+                AocError::parse("an energy level", "*")
+                    .at_line(2)
+                    .at_column(2)
+            ),
+        );
+        assert_eq!(
+            parse("98\n8").err(),
+            Some(AocError::parse("a row of 2 octopi", "8").at_line(2)),
+        );
+    }
+}
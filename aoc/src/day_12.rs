@@ -0,0 +1,652 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+
+use crate::error::{numbered_lines, AocError};
+use crate::render::RenderFormat;
+use crate::solution::{Answer, Result, Solution};
+
+// Graphviz colours for each size of cave
+const BIG_CAVE_COLOUR: &str = "lightblue";
+const LITTLE_CAVE_COLOUR: &str = "lightyellow";
+
+// every cave, mapped to the caves it connects to
+pub type Caves = HashMap<String, Vec<String>>;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Caves;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Caves> {
+    let mut caves: Caves = HashMap::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        if line.is_empty() {
+            continue;
+        }
+
+        let (start, end) = line
+            .split_once('-')
+            .filter(|(start, end)| is_cave_name(start) && is_cave_name(end))
+            .ok_or_else(|| {
+                AocError::parse("a connection like start-A", line).at_line(line_number)
+            })?;
+        // a path could go back and forth between two big caves forever
+        if !is_little(start) && !is_little(end) {
+            return Err(AocError::malformed(format!(
+                "line {}: big caves {} and {} are connected, so paths could go back and forth between them forever",
+                line_number, start, end
+            )));
+        }
+
+        caves
+            .entry(start.to_string())
+            .or_default()
+            .push(end.to_string());
+        caves
+            .entry(end.to_string())
+            .or_default()
+            .push(start.to_string());
+    }
+
+    Ok(caves)
+}
+
+// caves are either all upper case (big) or all lower case (little)
+fn is_cave_name(name: &str) -> bool {
+    !name.is_empty()
+        && (name.chars().all(|c| c.is_ascii_uppercase())
+            || name.chars().all(|c| c.is_ascii_lowercase()))
+}
+
+// little caves are all lower case, and there's only so many times a path can go into them
+fn is_little(cave: &str) -> bool {
+    cave.chars().all(|c| c.is_ascii_lowercase())
+}
+
+// The caves as a Graphviz graph, for looking at what an input actually is
+pub fn to_dot(caves: &Caves) -> String {
+    let mut dot = String::from("graph caves {\n    node [style=filled];\n");
+
+    let mut names: Vec<&String> = caves.keys().collect();
+    names.sort();
+    for name in names {
+        let colour = if is_little(name) {
+            LITTLE_CAVE_COLOUR
+        } else {
+            BIG_CAVE_COLOUR
+        };
+        let shape = if name == "start" || name == "end" {
+            "doublecircle"
+        } else {
+            "circle"
+        };
+        // writing to a String can't fail
+        let _ = writeln!(
+            dot,
+            "    \"{}\" [shape={}, fillcolor={}];",
+            name, shape, colour
+        );
+    }
+
+    // every connection is in the map both ways round, but is only drawn once
+    let connections: BTreeSet<(&str, &str)> = caves
+        .iter()
+        .flat_map(|(from, to)| to.iter().map(move |to| (from.as_str(), to.as_str())))
+        .map(|(from, to)| (from.min(to), from.max(to)))
+        .collect();
+    for (from, to) in connections {
+        let _ = writeln!(dot, "    \"{}\" -- \"{}\";", from, to);
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+// day 12 can only be drawn as a graph
+pub fn render(caves: &Caves, format: RenderFormat) -> Result<Vec<u8>> {
+    match format {
+        RenderFormat::Dot => Ok(to_dot(caves).into_bytes()),
+        _ => Err(AocError::unsolvable(
+            "day 12 is a graph, it can only be rendered with --dot",
+        )),
+    }
+}
+
+// routes that visit each little cave at most once
+pub fn part1(caves: &Caves) -> Result<usize> {
+    PathFinder::new(caves, RevisitPolicy::NoRepeats).count()
+}
+
+// routes where a single little cave may be visited twice
+pub fn part2(caves: &Caves) -> Result<usize> {
+    PathFinder::new(caves, RevisitPolicy::OneLittleCave(2)).count()
+}
+
+// How often a path may go into each little cave, big caves can always be visited again and the
+// start cave never can
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RevisitPolicy {
+    // every little cave at most once
+    NoRepeats,
+    // every little cave once, apart from a single one which may be visited up to this many times
+    OneLittleCave(usize),
+    // the number of visits allowed for each named little cave, with a default for the rest
+    Budget {
+        default: usize,
+        caves: HashMap<String, usize>,
+    },
+}
+
+impl RevisitPolicy {
+    fn allows(&self, route: &Route, cave: &str) -> bool {
+        let visits = route.visits.get(cave).copied().unwrap_or(0);
+        match self {
+            RevisitPolicy::NoRepeats => visits == 0,
+            RevisitPolicy::OneLittleCave(limit) => {
+                visits == 0
+                    || (visits < *limit
+                        && route.repeated_cave.is_none_or(|repeated| repeated == cave))
+            }
+            RevisitPolicy::Budget { default, caves } => {
+                visits < caves.get(cave).copied().unwrap_or(*default)
+            }
+        }
+    }
+}
+
+// Finds every path through the caves from start to end, by default the caves named start and end
+pub struct PathFinder<'a> {
+    caves: &'a Caves,
+    policy: RevisitPolicy,
+    start: &'a str,
+    end: &'a str,
+}
+
+impl<'a> PathFinder<'a> {
+    pub fn new(caves: &'a Caves, policy: RevisitPolicy) -> Self {
+        Self {
+            caves,
+            policy,
+            start: "start",
+            end: "end",
+        }
+    }
+
+    pub fn between(mut self, start: &'a str, end: &'a str) -> Self {
+        self.start = start;
+        self.end = end;
+        self
+    }
+
+    fn check_ends(&self) -> Result<()> {
+        for cave in [self.start, self.end] {
+            if !self.caves.contains_key(cave) {
+                return Err(AocError::unsolvable(format!("there is no {} cave", cave)));
+            }
+        }
+        Ok(())
+    }
+
+    // every path as the caves it goes through, one at a time so they needn't all fit in memory
+    pub fn paths(&self) -> Result<Paths<'_>> {
+        self.check_ends()?;
+
+        Ok(Paths {
+            finder: self,
+            in_progress_routes: vec![Route::new(self.start)],
+        })
+    }
+
+    // How many paths there are. Paths only differ in where they go next by which little caves
+    // they've been through and which one they've gone back to, so for everything but a budget
+    // the count from each of those states is worked out once and remembered.
+    pub fn count(&self) -> Result<usize> {
+        self.check_ends()?;
+
+        match self.policy {
+            RevisitPolicy::Budget { .. } => Ok(self.paths()?.count()),
+            _ => {
+                let graph = CaveGraph::new(self.caves, self.start, self.end);
+                let mut visited = VisitedCaves::default();
+                if let Some(little) = graph.little[graph.start] {
+                    visited.insert(little);
+                }
+                graph
+                    .count_from(
+                        graph.start,
+                        visited,
+                        None,
+                        &self.policy,
+                        &mut HashMap::new(),
+                    )
+                    .ok_or_else(|| AocError::unsolvable("there are too many paths to count"))
+            }
+        }
+    }
+}
+
+// the little caves a path has been through, one bit each
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+struct VisitedCaves(Vec<u64>);
+
+impl VisitedCaves {
+    fn contains(&self, little: usize) -> bool {
+        self.0
+            .get(little / 64)
+            .is_some_and(|word| word & (1 << (little % 64)) != 0)
+    }
+
+    fn insert(&mut self, little: usize) {
+        if self.0.len() <= little / 64 {
+            self.0.resize(little / 64 + 1, 0);
+        }
+        self.0[little / 64] |= 1 << (little % 64);
+    }
+}
+
+// the little cave that's been gone back to and how many times it's been visited
+type Repeated = Option<(usize, usize)>;
+
+// where a path is, where it's been, and what it's gone back to
+type CountState = (usize, VisitedCaves, Repeated);
+
+// The caves numbered so that the counting can remember states cheaply
+struct CaveGraph {
+    start: usize,
+    end: usize,
+    connections: Vec<Vec<usize>>,
+    // each little cave's bit in VisitedCaves
+    little: Vec<Option<usize>>,
+}
+
+impl CaveGraph {
+    fn new(caves: &Caves, start: &str, end: &str) -> Self {
+        let names: Vec<&str> = caves.keys().map(String::as_str).collect();
+        let index: HashMap<&str, usize> = names.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+
+        let mut little = Vec::new();
+        let mut little_caves = 0;
+        for name in &names {
+            little.push(is_little(name).then_some(little_caves));
+            if is_little(name) {
+                little_caves += 1;
+            }
+        }
+
+        Self {
+            start: index[start],
+            end: index[end],
+            connections: names
+                .iter()
+                .map(|name| caves[*name].iter().map(|to| index[to.as_str()]).collect())
+                .collect(),
+            little,
+        }
+    }
+
+    fn count_from(
+        &self,
+        cave: usize,
+        visited: VisitedCaves,
+        repeated: Repeated,
+        policy: &RevisitPolicy,
+        known: &mut HashMap<CountState, usize>,
+    ) -> Option<usize> {
+        if cave == self.end {
+            return Some(1);
+        }
+        let state = (cave, visited, repeated);
+        if let Some(count) = known.get(&state) {
+            return Some(*count);
+        }
+
+        let visited = &state.1;
+        let mut count: usize = 0;
+        for &next in self.connections[cave]
+            .iter()
+            .filter(|&&next| next != self.start)
+        {
+            let (visited, repeated) = match self.little[next] {
+                None => (visited.clone(), repeated),
+                Some(little) if !visited.contains(little) => {
+                    let mut visited = visited.clone();
+                    visited.insert(little);
+                    (visited, repeated)
+                }
+                Some(little) => match (policy, repeated) {
+                    (RevisitPolicy::OneLittleCave(limit), None) if *limit > 1 => {
+                        (visited.clone(), Some((little, 2)))
+                    }
+                    (RevisitPolicy::OneLittleCave(limit), Some((again, visits)))
+                        if again == little && visits < *limit =>
+                    {
+                        (visited.clone(), Some((little, visits + 1)))
+                    }
+                    _ => continue,
+                },
+            };
+            let paths = self.count_from(next, visited, repeated, policy, known)?;
+            count = count.checked_add(paths)?;
+        }
+
+        known.insert(state, count);
+        Some(count)
+    }
+}
+
+// the paths from a PathFinder, found depth first
+pub struct Paths<'a> {
+    finder: &'a PathFinder<'a>,
+    in_progress_routes: Vec<Route<'a>>,
+}
+
+impl<'a> Iterator for Paths<'a> {
+    type Item = Vec<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let PathFinder {
+            caves,
+            policy,
+            start,
+            end,
+        } = self.finder;
+
+        while let Some(route) = self.in_progress_routes.pop() {
+            if route.current_end == *end {
+                return Some(route.so_far);
+            }
+
+            // every cave in a route came from the map, so it's always there
+            for connection in caves[route.current_end].iter().map(String::as_str) {
+                if connection != *start
+                    && (!is_little(connection) || policy.allows(&route, connection))
+                {
+                    let mut new_route = route.clone();
+                    new_route.add_cave(connection);
+                    self.in_progress_routes.push(new_route);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Route<'a> {
+    so_far: Vec<&'a str>,
+    current_end: &'a str,
+    // how many times each little cave has been visited
+    visits: HashMap<&'a str, usize>,
+    // the first little cave to be visited more than once
+    repeated_cave: Option<&'a str>,
+}
+
+impl<'a> Route<'a> {
+    fn new(start: &'a str) -> Self {
+        let mut route = Self {
+            so_far: Vec::new(),
+            current_end: start,
+            visits: HashMap::new(),
+            repeated_cave: None,
+        };
+        route.add_cave(start);
+        route
+    }
+
+    fn add_cave(&mut self, cave: &'a str) {
+        self.so_far.push(cave);
+        self.current_end = cave;
+        if is_little(cave) {
+            let visits = self.visits.entry(cave).or_insert(0);
+            *visits += 1;
+            if *visits > 1 && self.repeated_cave.is_none() {
+                self.repeated_cave = Some(cave);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn part1_example1() -> Result<()> {
+        let test_data = ["start-A", "start-b", "A-c", "A-b", "b-d", "A-end", "b-end"];
+        assert_eq!(part1(&parse(&test_data.join("\n"))?)?, 10);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_example2() -> Result<()> {
+        let test_data = vec![
+            "dc-end", "HN-start", "start-kj", "dc-start", "dc-HN", "LN-dc", "HN-end", "kj-sa",
+            "kj-HN", "kj-dc",
+        ];
+        assert_eq!(part1(&parse(&test_data.join("\n"))?)?, 19);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_example3() -> Result<()> {
+        let test_data = vec![
+            "fs-end", "he-DX", "fs-he", "start-DX", "pj-DX", "end-zg", "zg-sl", "zg-pj", "pj-he",
+            "RW-he", "fs-DX", "pj-RW", "zg-RW", "start-pj", "he-WI", "zg-he", "pj-fs", "start-RW",
+        ];
+        assert_eq!(part1(&parse(&test_data.join("\n"))?)?, 226);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example1() -> Result<()> {
+        let test_data = ["start-A", "start-b", "A-c", "A-b", "b-d", "A-end", "b-end"];
+        assert_eq!(part2(&parse(&test_data.join("\n"))?)?, 36);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example2() -> Result<()> {
+        let test_data = vec![
+            "dc-end", "HN-start", "start-kj", "dc-start", "dc-HN", "LN-dc", "HN-end", "kj-sa",
+            "kj-HN", "kj-dc",
+        ];
+        assert_eq!(part2(&parse(&test_data.join("\n"))?)?, 103);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example3() -> Result<()> {
+        let test_data = vec![
+            "fs-end", "he-DX", "fs-he", "start-DX", "pj-DX", "end-zg", "zg-sl", "zg-pj", "pj-he",
+            "RW-he", "fs-DX", "pj-RW", "zg-RW", "start-pj", "he-WI", "zg-he", "pj-fs", "start-RW",
+        ];
+        assert_eq!(part2(&parse(&test_data.join("\n"))?)?, 3509);
+        Ok(())
+    }
+
+    #[test]
+    fn stream_paths() -> Result<()> {
+        let caves = parse("start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end")?;
+        let finder = PathFinder::new(&caves, RevisitPolicy::NoRepeats);
+
+        let mut paths: Vec<String> = finder.paths()?.map(|path| path.join(",")).collect();
+        paths.sort();
+        assert_eq!(paths.len(), 10);
+        assert_eq!(paths[0], "start,A,b,A,c,A,end");
+        assert!(paths.contains(&String::from("start,b,end")));
+        assert!(finder
+            .paths()?
+            .all(|path| path[0] == "start" && path[path.len() - 1] == "end"));
+        Ok(())
+    }
+
+    #[test]
+    fn revisit_policies() -> Result<()> {
+        let caves = parse("start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end")?;
+        let count = |policy| PathFinder::new(&caves, policy).count();
+
+        assert_eq!(count(RevisitPolicy::OneLittleCave(1))?, 10);
+        assert_eq!(count(RevisitPolicy::OneLittleCave(2))?, 36);
+
+        // b twice and nothing else is part 1 plus the paths through b twice
+        let budget = |b| RevisitPolicy::Budget {
+            default: 1,
+            caves: HashMap::from([(String::from("b"), b)]),
+        };
+        assert_eq!(count(budget(1))?, 10);
+        assert!(count(budget(2))? > 10 && count(budget(2))? < 36);
+        assert!(count(budget(3))? > count(budget(2))?);
+        Ok(())
+    }
+
+    #[test]
+    fn custom_start_and_end() -> Result<()> {
+        let caves = parse("start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end")?;
+        let finder = PathFinder::new(&caves, RevisitPolicy::NoRepeats).between("c", "d");
+
+        // start and end are just little caves when they're not the ends of the path
+        let mut paths: Vec<Vec<&str>> = finder.paths()?.collect();
+        paths.sort();
+        assert_eq!(paths.len(), 9);
+        assert_eq!(paths[0], vec!["c", "A", "b", "d"]);
+        assert!(paths.contains(&vec!["c", "A", "end", "A", "start", "b", "d"]));
+
+        let finder = PathFinder::new(&caves, RevisitPolicy::NoRepeats).between("c", "x");
+        assert_eq!(
+            finder.count().err(),
+            Some(AocError::unsolvable("there is no x cave"))
+        );
+        Ok(())
+    }
+
+    // little caves a0, a1, ... in a line, each joined to the next by two big caves
+    fn ladder(rungs: usize, bigs: &[&str]) -> Caves {
+        let name = |i: usize| -> String {
+            let mut name = String::new();
+            let mut i = i;
+            loop {
+                name.push((b'a' + (i % 26) as u8) as char);
+                i /= 26;
+                if i == 0 {
+                    return name;
+                }
+            }
+        };
+
+        let mut connections = vec![format!("start-{}", name(0))];
+        for i in 0..rungs {
+            for big in bigs {
+                connections.push(format!("{}-{}{}", name(i), big, name(i).to_uppercase()));
+                connections.push(format!("{}{}-{}", big, name(i).to_uppercase(), name(i + 1)));
+            }
+        }
+        connections.push(format!("{}-end", name(rungs)));
+        parse(&connections.join("\n")).expect("the ladder is made of valid connections")
+    }
+
+    #[test]
+    fn memoised_count_matches_paths() -> Result<()> {
+        let examples = [
+            "start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end",
+            "dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc",
+        ];
+        for caves in examples
+            .iter()
+            .map(|example| parse(example))
+            .chain([Ok(ladder(4, &["P", "Q"]))])
+        {
+            let caves = caves?;
+            for policy in [
+                RevisitPolicy::NoRepeats,
+                RevisitPolicy::OneLittleCave(1),
+                RevisitPolicy::OneLittleCave(2),
+                RevisitPolicy::OneLittleCave(3),
+            ] {
+                let finder = PathFinder::new(&caves, policy);
+                assert_eq!(finder.count()?, finder.paths()?.count());
+            }
+            let finder = PathFinder::new(&caves, RevisitPolicy::NoRepeats).between("end", "start");
+            assert_eq!(finder.count()?, finder.paths()?.count());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn memoised_count_large_graph() -> Result<()> {
+        // two ways across each of 40 rungs, far too many paths to go through one by one
+        assert_eq!(part1(&ladder(40, &["P", "Q"]))?, 1 << 40);
+        // more little caves than fit in one word of visited bits
+        assert_eq!(part1(&ladder(150, &["P"]))?, 1);
+        assert_eq!(
+            part1(&ladder(150, &["P", "Q"])).err(),
+            Some(AocError::unsolvable("there are too many paths to count"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_connection() {
+        assert!(parse("start-A\nA").is_err());
+    }
+
+    #[test]
+    fn bad_connections() {
+        assert_eq!(
+            parse("start-A\nA-\nA-end").err(),
+            Some(AocError::parse("a connection like start-A", "A-").at_line(2)),
+        );
+        assert!(parse("start-Ab").is_err());
+    }
+
+    #[test]
+    fn big_caves_together() {
+        assert_eq!(
+            parse("start-A\nA-b\nb-end\nA-BC").err(),
+            Some(AocError::malformed(
+                "line 4: big caves A and BC are connected, so paths could go back and forth between them forever"
+            )),
+        );
+        assert!(matches!(
+            parse("start-A\nA-A\nA-end"),
+            Err(AocError::MalformedInput(_))
+        ));
+    }
+
+    #[test]
+    fn dot() -> Result<()> {
+        let caves = parse("start-A\nA-b\nb-A\nA-end")?;
+        let dot = String::from_utf8(render(&caves, RenderFormat::Dot)?).unwrap_or_default();
+
+        assert!(dot.starts_with("graph caves {"));
+        assert!(dot.contains("\"A\" [shape=circle, fillcolor=lightblue];"));
+        assert!(dot.contains("\"b\" [shape=circle, fillcolor=lightyellow];"));
+        assert!(dot.contains("\"start\" [shape=doublecircle, fillcolor=lightyellow];"));
+        assert_eq!(dot.matches("\"A\" -- \"b\";").count(), 1);
+        assert_eq!(dot.matches(" -- ").count(), 3);
+        assert!(render(&caves, RenderFormat::Ansi).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn missing_end() -> Result<()> {
+        assert_eq!(
+            part1(&parse("start-A\nA-b")?).err(),
+            Some(AocError::unsolvable("there is no end cave"))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,488 @@
+use std::{collections::HashSet, fmt::Display, iter::Enumerate, slice, str::FromStr};
+
+use crate::error::{numbered_lines, parse_number, AocError};
+use crate::grid::Grid;
+use crate::log::debug;
+use crate::ocr;
+use crate::solution::{Answer, Result, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Manual;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
+
+// the transparent paper's dots and the fold instructions that go with them
+#[derive(Debug, Clone)]
+pub struct Manual {
+    pub dots: Vec<Dot>,
+    pub folds: Vec<Fold>,
+}
+
+pub fn parse(input: &str) -> Result<Manual> {
+    // need to parse dots
+    // need to parse instructions
+
+    let mut dots = Vec::new();
+    let mut folds = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        if line.is_empty() {
+            continue;
+        }
+
+        // fold
+        if line.starts_with('f') {
+            folds.push(Fold::from_str(line).map_err(|err| err.at_line(line_number))?);
+        } else {
+            // dot
+            dots.push(Dot::from_str(line).map_err(|err| err.at_line(line_number))?);
+        }
+    }
+
+    Ok(Manual { dots, folds })
+}
+
+// number of dots visible after the first fold
+pub fn part1(manual: &Manual) -> Result<usize> {
+    let paper = manual
+        .folding()
+        .next()
+        .ok_or_else(|| AocError::unsolvable("there are no fold instructions"))??;
+
+    Ok(paper.len())
+}
+
+// the letters the dots spell out once every fold is done
+pub fn part2(manual: &Manual) -> Result<String> {
+    let paper = manual
+        .folding()
+        .last()
+        .unwrap_or_else(|| Ok(Paper::new(manual.dots.iter().cloned())))?;
+    debug!(
+        "Folded paper:\n{}",
+        paper.plot().map(|&dot| if dot { '#' } else { '.' })
+    );
+
+    ocr::read(&paper.plot())
+}
+
+impl Manual {
+    // the paper after each of the manual's folds
+    pub fn folding(&self) -> Folds<'_> {
+        Paper::new(self.dots.iter().cloned()).folding(&self.folds)
+    }
+}
+
+// The dots that can be seen on the paper at some point in the folding
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paper {
+    dots: HashSet<Dot>,
+}
+
+// the smallest rectangle holding every dot, edges included
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min_x: usize,
+    pub min_y: usize,
+    pub max_x: usize,
+    pub max_y: usize,
+}
+
+impl Bounds {
+    pub fn width(&self) -> usize {
+        self.max_x - self.min_x + 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.max_y - self.min_y + 1
+    }
+}
+
+impl Paper {
+    pub fn new(dots: impl IntoIterator<Item = Dot>) -> Self {
+        Self {
+            dots: dots.into_iter().collect(),
+        }
+    }
+
+    pub fn dots(&self) -> &HashSet<Dot> {
+        &self.dots
+    }
+
+    // dots that have been folded onto each other only count once
+    pub fn len(&self) -> usize {
+        self.dots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dots.is_empty()
+    }
+
+    pub fn bounds(&self) -> Option<Bounds> {
+        let first = self.dots.iter().next()?;
+        Some(self.dots.iter().fold(
+            Bounds {
+                min_x: first.x,
+                min_y: first.y,
+                max_x: first.x,
+                max_y: first.y,
+            },
+            |bounds, dot| Bounds {
+                min_x: bounds.min_x.min(dot.x),
+                min_y: bounds.min_y.min(dot.y),
+                max_x: bounds.max_x.max(dot.x),
+                max_y: bounds.max_y.max(dot.y),
+            },
+        ))
+    }
+
+    // Applies the folds one at a time, stopping after the first one that can't be done
+    pub fn folding(self, folds: &[Fold]) -> Folds<'_> {
+        Folds {
+            paper: Some(self),
+            folds: folds.iter().enumerate(),
+        }
+    }
+
+    // number is which fold this is, counting from 1, for saying which one went wrong
+    fn fold(&self, number: usize, fold: &Fold) -> Result<Paper> {
+        let mut dots = HashSet::with_capacity(self.dots.len());
+        for dot in &self.dots {
+            if dot.position(fold.direction) == fold.value {
+                return Err(AocError::unsolvable(format!(
+                    "fold {} along {} lands on the dot at {}",
+                    number, fold, dot
+                )));
+            }
+            dots.insert(dot.fold(fold).ok_or_else(|| {
+                AocError::unsolvable(format!(
+                    "fold {} along {} moves the dot at {} off the paper",
+                    number, fold, dot
+                ))
+            })?);
+        }
+
+        Ok(Paper { dots })
+    }
+
+    // the dots from the top left of the paper, on a grid just big enough to hold them
+    pub fn plot(&self) -> Grid<bool> {
+        let (width, height) = self
+            .bounds()
+            .map_or((0, 0), |bounds| (bounds.max_x + 1, bounds.max_y + 1));
+
+        let mut paper = Grid::filled(width, height, false);
+        for dot in &self.dots {
+            paper[(dot.x, dot.y)] = true;
+        }
+        paper
+    }
+}
+
+// The paper after each fold, see Paper::folding
+pub struct Folds<'a> {
+    // None once a fold has failed
+    paper: Option<Paper>,
+    folds: Enumerate<slice::Iter<'a, Fold>>,
+}
+
+impl Iterator for Folds<'_> {
+    type Item = Result<Paper>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, fold) = self.folds.next()?;
+        let folded = self.paper.take()?.fold(index + 1, fold);
+
+        if let Ok(paper) = &folded {
+            debug!(
+                "Fold {} along {} leaves {} dots",
+                index + 1,
+                fold,
+                paper.len()
+            );
+            self.paper = Some(paper.clone());
+        }
+        Some(folded)
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Dot {
+    x: usize,
+    y: usize,
+}
+
+impl Dot {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn position(&self, direction: Direction) -> usize {
+        match direction {
+            Direction::X => self.x,
+            Direction::Y => self.y,
+        }
+    }
+
+    // Where the dot ends up after the fold, if it stays on the paper. If we're folding along
+    // x = 5, then values > 5 get moved to the difference between value and x subtracted from x.
+    pub fn fold(&self, instruction: &Fold) -> Option<Dot> {
+        let position = self.position(instruction.direction);
+        let folded = if position > instruction.value {
+            // a fold past the middle of the paper would push dots off the other edge
+            (2 * instruction.value).checked_sub(position)?
+        } else {
+            position
+        };
+
+        Some(match instruction.direction {
+            Direction::X => Dot::new(folded, self.y),
+            Direction::Y => Dot::new(self.x, folded),
+        })
+    }
+}
+
+impl Display for Dot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl FromStr for Dot {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| AocError::parse("a dot like 6,10", s))?;
+
+        Ok(Self::new(
+            parse_number(x, "an x coordinate").map_err(|err| err.at_column(1))?,
+            parse_number(y, "a y coordinate").map_err(|err| err.at_column(x.len() + 2))?,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    X,
+    Y,
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::X => write!(f, "x"),
+            Direction::Y => write!(f, "y"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fold {
+    pub direction: Direction,
+    pub value: usize,
+}
+
+// the fold line, like x=5
+impl Display for Fold {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.direction, self.value)
+    }
+}
+
+impl FromStr for Fold {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        // fold along x=5
+        // need to separate out the x=5 part
+        const PREFIX: &str = "fold along ";
+        let (axis, value) = s
+            .strip_prefix(PREFIX)
+            .and_then(|instruction| instruction.split_once('='))
+            .ok_or_else(|| AocError::parse("a fold like fold along x=5", s))?;
+        let direction = match axis {
+            "x" => Direction::X,
+            "y" => Direction::Y,
+            _ => return Err(AocError::parse("x or y", axis).at_column(PREFIX.len() + 1)),
+        };
+        let value = parse_number(value, "a fold position")
+            .map_err(|err| err.at_column(PREFIX.len() + axis.len() + 2))?;
+
+        Ok(Self { direction, value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> String {
+        let test_data: Vec<&str> = vec![
+            "6,10",
+            "0,14",
+            "9,10",
+            "0,3",
+            "10,4",
+            "4,11",
+            "6,0",
+            "6,12",
+            "4,1",
+            "0,13",
+            "10,12",
+            "3,4",
+            "3,0",
+            "8,4",
+            "1,10",
+            "2,14",
+            "8,10",
+            "9,0",
+            "",
+            "fold along y=7",
+            "fold along x=5",
+        ];
+        test_data.join("\n")
+    }
+
+    #[test]
+    fn part1_example1() -> Result<()> {
+        assert_eq!(part1(&parse(&example())?)?, 17);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example1() -> Result<()> {
+        // the puzzle's example is a square, which isn't a letter
+        assert_eq!(
+            part2(&parse(&example())?).err(),
+            Some(AocError::unsolvable(
+                "letter 1 isn't one that's known:\n####\n#...\n#...\n#...\n####\n...."
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn part2_letters() -> Result<()> {
+        let mut test_data = vec![
+            "27,12", "26,0", "28,11", "3,1", "28,2", "25,10", "0,9", "1,3", "26,9", "25,3", "0,4",
+            "3,8", "28,7", "3,5", "22,0", "7,12", "23,1", "20,11", "5,10", "20,2", "5,3", "8,9",
+            "23,8", "8,4", "6,7", "7,5", "11,12", "12,0", "10,11", "15,1", "10,2", "18,9", "18,4",
+            "15,8", "11,5", "16,7",
+        ];
+        test_data.extend(["", "fold along y=6", "fold along x=14"]);
+
+        assert_eq!(part2(&parse(&test_data.join("\n"))?)?, "AOC");
+        Ok(())
+    }
+
+    #[test]
+    fn bad_instructions() {
+        assert_eq!(
+            parse("6,10\n0,x").err(),
+            Some(
+                AocError::parse("a y coordinate", "x")
+                    .at_line(2)
+                    .at_column(3)
+            ),
+        );
+        assert_eq!(
+            parse("6,10\nfold along z=5").err(),
+            Some(AocError::parse("x or y", "z").at_line(2).at_column(12)),
+        );
+        assert!(parse("fold along x").is_err());
+    }
+
+    #[test]
+    fn fold_off_the_paper() -> Result<()> {
+        assert!(matches!(
+            part1(&parse("10,4\nfold along x=2")?).err(),
+            Some(AocError::Unsolvable(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn fold_history() -> Result<()> {
+        let history = parse(&example())?
+            .folding()
+            .map(|paper| paper.map(|paper| (paper.len(), paper.bounds())))
+            .collect::<Result<Vec<_>>>()?;
+
+        assert_eq!(
+            history,
+            vec![
+                (
+                    17,
+                    Some(Bounds {
+                        min_x: 0,
+                        min_y: 0,
+                        max_x: 10,
+                        max_y: 4
+                    })
+                ),
+                (
+                    16,
+                    Some(Bounds {
+                        min_x: 0,
+                        min_y: 0,
+                        max_x: 4,
+                        max_y: 4
+                    })
+                ),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn any_folds() -> Result<()> {
+        let paper = Paper::new([Dot::new(0, 0), Dot::new(4, 1), Dot::new(3, 3)]);
+        let folds = ["fold along x=2", "fold along y=2", "fold along y=3"]
+            .iter()
+            .map(|fold| fold.parse())
+            .collect::<Result<Vec<Fold>>>()?;
+
+        let folded = paper.folding(&folds).last().unwrap_or_else(|| {
+            Err(AocError::unsolvable(
+                "there should be a paper for every fold",
+            ))
+        })?;
+        assert_eq!(
+            folded,
+            Paper::new([Dot::new(0, 0), Dot::new(0, 1), Dot::new(1, 1)])
+        );
+        assert_eq!(folded.bounds().map(|bounds| bounds.width()), Some(2));
+        Ok(())
+    }
+
+    #[test]
+    fn fold_on_a_dot() -> Result<()> {
+        let manual = parse("1,1\n6,2\nfold along y=4\nfold along x=6\nfold along y=1")?;
+        let mut folding = manual.folding();
+
+        assert!(folding.next().is_some_and(|paper| paper.is_ok()));
+        assert_eq!(
+            folding.next().and_then(|paper| paper.err()),
+            Some(AocError::unsolvable(
+                "fold 2 along x=6 lands on the dot at 6,2"
+            ))
+        );
+        assert!(folding.next().is_none());
+        Ok(())
+    }
+}
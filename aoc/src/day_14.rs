@@ -0,0 +1,570 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::error::{numbered_lines, AocError};
+use crate::grid::Grid;
+use crate::log::debug;
+use crate::solution::{Answer, Result, Solution};
+
+pub const PART1_STEPS: usize = 10;
+pub const PART2_STEPS: usize = 40;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Polymer;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
+
+// A polymer as the number of each pair of neighbouring elements, along with the insertion rules
+// that grow it. Elements are numbered in the order they're first seen.
+#[derive(Debug, Clone)]
+pub struct Polymer {
+    elements: Vec<String>,
+    // the first element never changes, and it's the only one that isn't the second of a pair
+    first: Option<usize>,
+    pairs: HashMap<(usize, usize), u64>,
+    // the elements each pair has inserted between them, ie (C, H) -> [B]
+    rules: HashMap<(usize, usize), Vec<usize>>,
+    steps: usize,
+}
+
+pub fn parse(input: &str) -> Result<Polymer> {
+    let mut lines = numbered_lines(input).filter(|(_, line)| !line.is_empty());
+    let (line_number, template) = lines
+        .next()
+        .ok_or_else(|| AocError::parse("a polymer template", ""))?;
+
+    let mut polymer = Polymer {
+        elements: Vec::new(),
+        first: None,
+        pairs: HashMap::new(),
+        rules: HashMap::new(),
+        steps: 0,
+    };
+
+    let template = split_elements(template).ok_or_else(|| {
+        AocError::parse("a polymer template like NNCB", template).at_line(line_number)
+    })?;
+    let template: Vec<usize> = template
+        .into_iter()
+        .map(|element| polymer.element(element))
+        .collect();
+    polymer.first = template.first().copied();
+    for pair in template.windows(2) {
+        *polymer.pairs.entry((pair[0], pair[1])).or_insert(0) += 1;
+    }
+
+    for (line_number, formula) in lines {
+        let ((first, second), inserted) =
+            parse_rule(formula).map_err(|err| err.at_line(line_number))?;
+        let pair = (polymer.element(first), polymer.element(second));
+        let inserted = inserted
+            .into_iter()
+            .map(|element| polymer.element(element))
+            .collect();
+        polymer.rules.insert(pair, inserted);
+    }
+
+    Ok(polymer)
+}
+
+// Elements are a capital letter followed by any number of small ones, like N or Ca
+fn split_elements(s: &str) -> Option<Vec<&str>> {
+    let mut elements = Vec::new();
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                elements.push(&s[start..i]);
+            }
+            start = i;
+        } else if !c.is_ascii_lowercase() || i == 0 {
+            return None;
+        }
+    }
+    if !s.is_empty() {
+        elements.push(&s[start..]);
+    }
+    Some(elements)
+}
+
+// CH -> B, or with longer names and more elements to insert, CaH -> BNa
+fn parse_rule(formula: &str) -> Result<((&str, &str), Vec<&str>)> {
+    let (raw_pair, raw_result) = formula
+        .split_once(" -> ")
+        .ok_or_else(|| AocError::parse("a rule like CH -> B", formula))?;
+
+    let pair = match split_elements(raw_pair).as_deref() {
+        Some(&[first, second]) => (first, second),
+        _ => return Err(AocError::parse("a pair of elements", raw_pair).at_column(1)),
+    };
+    let inserted = split_elements(raw_result)
+        .filter(|inserted| !inserted.is_empty())
+        .ok_or_else(|| {
+            AocError::parse("elements to insert", raw_result)
+                .at_column(raw_pair.len() + " -> ".len() + 1)
+        })?;
+
+    Ok((pair, inserted))
+}
+
+impl Polymer {
+    // the number for an element, giving it one if it hasn't been seen before
+    fn element(&mut self, name: &str) -> usize {
+        match self.elements.iter().position(|element| element == name) {
+            Some(index) => index,
+            None => {
+                self.elements.push(name.to_string());
+                self.elements.len() - 1
+            }
+        }
+    }
+
+    // how many times the rules have been applied
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    // Inserts elements between every pair that has a rule, all at once. Pairs without a rule are
+    // left as they are.
+    pub fn step(&mut self) -> Result<()> {
+        let mut pairs = HashMap::with_capacity(self.pairs.len());
+        for (&pair, &count) in &self.pairs {
+            for new_pair in self.products(pair) {
+                let total: &mut u64 = pairs.entry(new_pair).or_insert(0);
+                *total = total.checked_add(count).ok_or_else(|| {
+                    AocError::unsolvable(format!(
+                        "the polymer is too long to count after {} steps",
+                        self.steps + 1
+                    ))
+                })?;
+            }
+        }
+
+        self.pairs = pairs;
+        self.steps += 1;
+        Ok(())
+    }
+
+    // the pairs a pair turns into after one step, ie (C, H) -> (C, B) + (B, H)
+    fn products(&self, (first, second): (usize, usize)) -> Vec<(usize, usize)> {
+        let chain = match self.rules.get(&(first, second)) {
+            Some(inserted) => [&[first][..], inserted, &[second]].concat(),
+            None => vec![first, second],
+        };
+        chain.windows(2).map(|pair| (pair[0], pair[1])).collect()
+    }
+
+    // How many of each element there'll be after another number of steps, without taking them
+    // one at a time. A step is a matrix saying how many of each pair every pair turns into, so
+    // any number of steps is that matrix raised to a power, done by repeated squaring.
+    pub fn histogram_after(&self, steps: u64, counting: Counting) -> Result<BTreeMap<&str, u128>> {
+        if counting == Counting::Modulo(0) {
+            return Err(AocError::malformed("counts can't be kept modulo 0"));
+        }
+
+        // every pair the polymer can ever have, numbered for the rows and columns of the matrix
+        let mut pairs: Vec<(usize, usize)> = self.pairs.keys().copied().collect();
+        let mut index: HashMap<(usize, usize), usize> = pairs
+            .iter()
+            .enumerate()
+            .map(|(i, &pair)| (pair, i))
+            .collect();
+        let mut transitions = Vec::new();
+        let mut next = 0;
+        while let Some(&pair) = pairs.get(next) {
+            let mut products = Vec::new();
+            for product in self.products(pair) {
+                let product_index = *index.entry(product).or_insert_with(|| {
+                    pairs.push(product);
+                    pairs.len() - 1
+                });
+                products.push(product_index);
+            }
+            transitions.push(products);
+            next += 1;
+        }
+
+        // each column is a pair, each row how many of that pair it turns into
+        let mut step = Grid::filled(pairs.len(), pairs.len(), 0);
+        for (from, products) in transitions.iter().enumerate() {
+            for &to in products {
+                step[(from, to)] += 1;
+            }
+        }
+        let too_many = || {
+            AocError::unsolvable(format!(
+                "there are too many elements to count exactly after {} steps",
+                steps
+            ))
+        };
+        let jump = power(&step, steps, counting).ok_or_else(too_many)?;
+
+        let mut histogram = BTreeMap::new();
+        if let Some(first) = self.first {
+            histogram.insert(self.elements[first].as_str(), counting.reduce(1));
+        }
+        for (to, &(_, second)) in pairs.iter().enumerate() {
+            let mut count = 0;
+            for (from, pair) in pairs.iter().enumerate() {
+                let start = counting.reduce(*self.pairs.get(pair).unwrap_or(&0) as u128);
+                let term = counting
+                    .multiply(jump[(from, to)], start)
+                    .ok_or_else(too_many)?;
+                count = counting.add(count, term).ok_or_else(too_many)?;
+            }
+            let total = histogram.entry(self.elements[second].as_str()).or_insert(0);
+            *total = counting.add(*total, count).ok_or_else(too_many)?;
+        }
+        // modulo counts of 0 are kept, there may be a multiple of the modulus of that element
+        if counting == Counting::Exact {
+            histogram.retain(|_, count| *count > 0);
+        }
+        Ok(histogram)
+    }
+
+    // How many of each element there are, by name
+    pub fn histogram(&self) -> BTreeMap<&str, u64> {
+        let mut histogram = BTreeMap::new();
+        if let Some(first) = self.first {
+            histogram.insert(self.elements[first].as_str(), 1);
+        }
+        // only count the second element of each pair, the first has been counted already
+        for (&(_, second), &count) in &self.pairs {
+            *histogram.entry(self.elements[second].as_str()).or_insert(0) += count;
+        }
+        histogram
+    }
+
+    // the total number of elements
+    pub fn len(&self) -> u64 {
+        self.histogram().values().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.first.is_none()
+    }
+}
+
+// How counts are kept when jumping ahead with Polymer::histogram_after
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Counting {
+    // the real counts, for as long as they fit in a u128
+    Exact,
+    // counts modulo a number, for polymers far too long to count
+    Modulo(u64),
+}
+
+impl Counting {
+    fn add(self, a: u128, b: u128) -> Option<u128> {
+        match self {
+            Counting::Exact => a.checked_add(b),
+            // both are below the modulus, which fits in a u64, so this can't overflow
+            Counting::Modulo(modulus) => Some((a + b) % modulus as u128),
+        }
+    }
+
+    fn multiply(self, a: u128, b: u128) -> Option<u128> {
+        match self {
+            Counting::Exact => a.checked_mul(b),
+            Counting::Modulo(modulus) => Some(a * b % modulus as u128),
+        }
+    }
+
+    fn reduce(self, a: u128) -> u128 {
+        match self {
+            Counting::Exact => a,
+            Counting::Modulo(modulus) => a % modulus as u128,
+        }
+    }
+}
+
+// the product of two square matrices, None if a count overflowed
+fn multiply(a: &Grid<u128>, b: &Grid<u128>, counting: Counting) -> Option<Grid<u128>> {
+    let size = a.width();
+    let mut product = Grid::filled(size, size, 0);
+    for ((column, row), cell) in product.iter_mut() {
+        for k in 0..size {
+            let term = counting.multiply(a[(k, row)], b[(column, k)])?;
+            *cell = counting.add(*cell, term)?;
+        }
+    }
+    Some(product)
+}
+
+// matrix to the power of exponent, squaring as it goes so it takes log2(exponent) steps
+fn power(matrix: &Grid<u128>, exponent: u64, counting: Counting) -> Option<Grid<u128>> {
+    let size = matrix.width();
+    let mut result = Grid::filled(size, size, 0);
+    for i in 0..size {
+        result[(i, i)] = counting.reduce(1);
+    }
+
+    let mut square = matrix.clone();
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = multiply(&result, &square, counting)?;
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            square = multiply(&square, &square, counting)?;
+        }
+    }
+    Some(result)
+}
+
+pub fn part1(polymer: &Polymer) -> Result<u64> {
+    polymerise(polymer, PART1_STEPS)
+}
+
+pub fn part2(polymer: &Polymer) -> Result<u64> {
+    polymerise(polymer, PART2_STEPS)
+}
+
+// difference between the most and least common element after the given number of steps
+pub fn polymerise(polymer: &Polymer, steps: usize) -> Result<u64> {
+    let mut polymer = polymer.clone();
+    for _ in 0..steps {
+        polymer.step()?;
+    }
+
+    let histogram = polymer.histogram();
+    debug!("Element counts after {} steps: {:?}", steps, histogram);
+
+    let max = histogram.values().max().unwrap_or(&0);
+    let min = histogram.values().min().unwrap_or(&0);
+    Ok(max - min)
+}
+
+// the same as polymerise, jumping straight to the last step
+pub fn polymerise_by_matrix(polymer: &Polymer, steps: u64) -> Result<u128> {
+    let histogram = polymer.histogram_after(steps, Counting::Exact)?;
+
+    let max = histogram.values().max().unwrap_or(&0);
+    let min = histogram.values().min().unwrap_or(&0);
+    Ok(max - min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn part1_example1() -> Result<()> {
+        let test_data = vec![
+            "NNCB", "", "CH -> B", "HH -> N", "CB -> H", "NH -> C", "HB -> C", "HC -> B",
+            "HN -> C", "NN -> C", "BH -> H", "NC -> B", "NB -> B", "BN -> B", "BB -> N", "BC -> B",
+            "CC -> N", "CN -> C",
+        ];
+        assert_eq!(part1(&parse(&test_data.join("\n"))?)?, 1588);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example1() -> Result<()> {
+        let test_data = vec![
+            "NNCB", "", "CH -> B", "HH -> N", "CB -> H", "NH -> C", "HB -> C", "HC -> B",
+            "HN -> C", "NN -> C", "BH -> H", "NC -> B", "NB -> B", "BN -> B", "BB -> N", "BC -> B",
+            "CC -> N", "CN -> C",
+        ];
+        assert_eq!(part2(&parse(&test_data.join("\n"))?)?, 2188189693529);
+        Ok(())
+    }
+
+    #[test]
+    fn one_step() -> Result<()> {
+        let test_data = vec![
+            "NNCB", "", "CH -> B", "HH -> N", "CB -> H", "NH -> C", "HB -> C", "HC -> B",
+            "HN -> C", "NN -> C", "BH -> H", "NC -> B", "NB -> B", "BN -> B", "BB -> N", "BC -> B",
+            "CC -> N", "CN -> C",
+        ];
+        assert_eq!(polymerise(&parse(&test_data.join("\n"))?, 1)?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn histograms() -> Result<()> {
+        let test_data = vec![
+            "NNCB", "", "CH -> B", "HH -> N", "CB -> H", "NH -> C", "HB -> C", "HC -> B",
+            "HN -> C", "NN -> C", "BH -> H", "NC -> B", "NB -> B", "BN -> B", "BB -> N", "BC -> B",
+            "CC -> N", "CN -> C",
+        ];
+        let mut polymer = parse(&test_data.join("\n"))?;
+        assert_eq!(
+            polymer.histogram(),
+            BTreeMap::from([("B", 1), ("C", 1), ("N", 2)])
+        );
+
+        // NCNBCHB
+        polymer.step()?;
+        assert_eq!(
+            polymer.histogram(),
+            BTreeMap::from([("B", 2), ("C", 2), ("H", 1), ("N", 2)])
+        );
+
+        for _ in 1..10 {
+            polymer.step()?;
+        }
+        assert_eq!(polymer.steps(), 10);
+        assert_eq!(polymer.len(), 3073);
+        assert_eq!(polymer.histogram()["B"], 1749);
+        assert_eq!(polymer.histogram()["H"], 161);
+        Ok(())
+    }
+
+    #[test]
+    fn longer_elements_and_insertions() -> Result<()> {
+        // HeLi -> HeBeCLi -> HeBeBeCBeLi, pairs without a rule stay as they are
+        let mut polymer = parse(
+            "HeLi
+
+HeLi -> BeC
+CLi -> Be
+BeC -> Be",
+        )?;
+        polymer.step()?;
+        assert_eq!(
+            polymer.histogram(),
+            BTreeMap::from([("Be", 1), ("C", 1), ("He", 1), ("Li", 1)])
+        );
+
+        polymer.step()?;
+        assert_eq!(
+            polymer.histogram(),
+            BTreeMap::from([("Be", 3), ("C", 1), ("He", 1), ("Li", 1)])
+        );
+        assert_eq!(polymerise(&parse("He")?, 5)?, 0);
+        assert_eq!(
+            polymerise(
+                &parse(
+                    "He
+
+HeHe -> He"
+                )?,
+                5
+            )?,
+            0
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn matrix_matches_steps() -> Result<()> {
+        let test_data = vec![
+            "NNCB", "", "CH -> B", "HH -> N", "CB -> H", "NH -> C", "HB -> C", "HC -> B",
+            "HN -> C", "NN -> C", "BH -> H", "NC -> B", "NB -> B", "BN -> B", "BB -> N", "BC -> B",
+            "CC -> N", "CN -> C",
+        ];
+        let mut polymer = parse(&test_data.join("\n"))?;
+        let start = polymer.clone();
+
+        for steps in 0..=20 {
+            let stepped: BTreeMap<&str, u128> = polymer
+                .histogram()
+                .into_iter()
+                .map(|(element, count)| (element, count as u128))
+                .collect();
+            assert_eq!(start.histogram_after(steps, Counting::Exact)?, stepped);
+            polymer.step()?;
+        }
+        assert_eq!(polymerise_by_matrix(&start, 40)?, 2188189693529);
+
+        let polymer = parse("HeLi\n\nHeLi -> BeC\nCLi -> Be\nBeC -> Be")?;
+        let mut stepped = polymer.clone();
+        for _ in 0..5 {
+            stepped.step()?;
+        }
+        assert_eq!(
+            polymer
+                .histogram_after(5, Counting::Exact)?
+                .values()
+                .sum::<u128>(),
+            stepped.len() as u128
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn matrix_modulo() -> Result<()> {
+        let test_data = vec![
+            "NNCB", "", "CH -> B", "HH -> N", "CB -> H", "NH -> C", "HB -> C", "HC -> B",
+            "HN -> C", "NN -> C", "BH -> H", "NC -> B", "NB -> B", "BN -> B", "BB -> N", "BC -> B",
+            "CC -> N", "CN -> C",
+        ];
+        let polymer = parse(&test_data.join("\n"))?;
+        const MODULUS: u64 = 1_000_000_007;
+
+        let exact = polymer.histogram_after(40, Counting::Exact)?;
+        let modulo = polymer.histogram_after(40, Counting::Modulo(MODULUS))?;
+        for (element, count) in exact {
+            assert_eq!(modulo[element], count % MODULUS as u128);
+        }
+
+        // every pair has a rule, so each step doubles the gaps between elements
+        let steps = 1_000_000_000_000;
+        let mut doubled: u128 = 1;
+        let mut base: u128 = 2;
+        let mut exponent = steps;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                doubled = doubled * base % MODULUS as u128;
+            }
+            base = base * base % MODULUS as u128;
+            exponent >>= 1;
+        }
+        let length: u128 = polymer
+            .histogram_after(steps, Counting::Modulo(MODULUS))?
+            .values()
+            .sum();
+        assert_eq!(
+            length % MODULUS as u128,
+            (3 * doubled + 1) % MODULUS as u128
+        );
+
+        assert!(matches!(
+            polymer.histogram_after(200, Counting::Exact),
+            Err(AocError::Unsolvable(_))
+        ));
+        assert!(polymer.histogram_after(1, Counting::Modulo(0)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn bad_rules() {
+        assert_eq!(
+            parse("NNCB\n\nCH -> B\nHHH -> N").err(),
+            Some(
+                AocError::parse("a pair of elements", "HHH")
+                    .at_line(4)
+                    .at_column(1)
+            ),
+        );
+        assert_eq!(
+            parse("NNCB\n\nCH -> bC").err(),
+            Some(
+                AocError::parse("elements to insert", "bC")
+                    .at_line(3)
+                    .at_column(7)
+            ),
+        );
+        assert_eq!(
+            parse("NN1B\n\nCH -> B").err(),
+            Some(AocError::parse("a polymer template like NNCB", "NN1B").at_line(1)),
+        );
+        assert_eq!(
+            parse("NNCB\n\nCH => B").err(),
+            Some(AocError::parse("a rule like CH -> B", "CH => B").at_line(3)),
+        );
+    }
+}
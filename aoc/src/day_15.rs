@@ -0,0 +1,608 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet},
+};
+
+use crate::error::{parse_digit, AocError};
+use crate::grid::{offset_within, Grid, ORTHOGONAL};
+use crate::log::{debug, trace};
+use crate::render::{grey, highlight, Image, RenderFormat, RED};
+use crate::solution::{Answer, Result, Solution};
+
+// part 2's cave is the input tiled five times in each direction
+pub const PART2_TILES: u32 = 5;
+
+// risk levels above this wrap back round to 1
+pub const RISK_WRAP: u32 = 9;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = RiskMap;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
+
+// the risk level of every position in a single tile of the cave
+#[derive(Debug)]
+pub struct RiskMap {
+    risks: Grid<u32>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Node {
+    pub x: usize,
+    pub y: usize,
+    pub cost: u32,
+}
+
+// The whole cave, made of copies of the risk map side by side. Nodes are worked out as they're
+// asked for rather than stored, so large tilings don't need memory for every position.
+pub struct Graph<'a> {
+    risk_map: &'a RiskMap,
+    wrap: u32,
+    width: usize,
+    height: usize,
+}
+
+impl Graph<'_> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // each tile to the right or down adds one to the risk of the tile before it
+    pub fn get_node(&self, x: i32, y: i32) -> Option<Node> {
+        if y < 0 || x < 0 || y as usize >= self.height || x as usize >= self.width {
+            return None;
+        }
+
+        let (x, y) = (x as usize, y as usize);
+        let risks = &self.risk_map.risks;
+        let (width, height) = (risks.width(), risks.height());
+        let tile_distance = (x / width + y / height) as u32;
+        let risk = risks[(x % width, y % height)] + tile_distance;
+
+        Some(Node {
+            x,
+            y,
+            cost: (risk - 1) % self.wrap + 1,
+        })
+    }
+
+    // the top left and bottom right corners, where the puzzle starts and ends
+    pub fn corners(&self) -> ((usize, usize), (usize, usize)) {
+        ((0, 0), (self.width - 1, self.height - 1))
+    }
+
+    pub fn dijkstra(&self, start: (usize, usize), goal: (usize, usize)) -> Option<Path> {
+        self.search(start, goal, &NoHeuristic)
+    }
+
+    pub fn a_star(&self, start: (usize, usize), goal: (usize, usize)) -> Option<Path> {
+        self.search(start, goal, &Manhattan)
+    }
+
+    // The lowest risk path between two positions, None if either is off the map or there's no way
+    // between them. Nodes come off a priority queue ordered by their risk so far plus the
+    // heuristic's estimate of the rest, stale entries are skipped rather than removed.
+    pub fn search(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        heuristic: &impl Heuristic,
+    ) -> Option<Path> {
+        let start = self.get_node(start.0 as i32, start.1 as i32)?;
+        let goal = self.get_node(goal.0 as i32, goal.1 as i32)?;
+
+        let mut open_set = BinaryHeap::new();
+        open_set.push(Reverse((
+            heuristic.estimate(&start, &goal),
+            0,
+            self.index(&start),
+        )));
+
+        let mut state = SearchState::new(self.width * self.height);
+        state.costs[self.index(&start)] = 0;
+
+        while let Some(Reverse((_, cost, index))) = open_set.pop() {
+            trace!("Iterating, open_set size: {}", open_set.len());
+            if cost > state.costs[index] {
+                continue;
+            }
+
+            let (x, y) = self.position(index);
+            if (x, y) == (goal.x, goal.y) {
+                return Some(self.reconstruct_path(&state.came_from, index, cost));
+            }
+
+            let neighbours = ORTHOGONAL
+                .iter()
+                .enumerate()
+                .filter_map(|(direction, &(dx, dy))| {
+                    let (x, y) = offset_within(x, y, dx, dy, self.width, self.height)?;
+                    Some((direction, self.get_node(x as i32, y as i32)?))
+                });
+
+            for (direction, neighbour_node) in neighbours {
+                let neighbour = self.index(&neighbour_node);
+                let tentative_cost = cost + neighbour_node.cost;
+
+                if tentative_cost < state.costs[neighbour] {
+                    state.costs[neighbour] = tentative_cost;
+                    state.came_from[neighbour] = direction as u8 + 1;
+                    open_set.push(Reverse((
+                        tentative_cost + heuristic.estimate(&neighbour_node, &goal),
+                        tentative_cost,
+                        neighbour,
+                    )));
+                }
+            }
+        }
+
+        None
+    }
+
+    // the risk levels as digits, with the path picked out in colour
+    pub fn render_ansi(&self, path: &Path) -> String {
+        let on_path = positions(path);
+        let mut rendered = String::new();
+
+        for y in 0..self.height {
+            for node in (0..self.width).filter_map(|x| self.get_node(x as i32, y as i32)) {
+                if on_path.contains(&(node.x, node.y)) {
+                    rendered.push_str(&highlight(node.cost));
+                } else {
+                    rendered.push_str(&node.cost.to_string());
+                }
+            }
+            rendered.push('\n');
+        }
+        rendered
+    }
+
+    // the risk levels in shades of grey, brighter is riskier, with the path in red
+    pub fn render_image(&self, path: &Path) -> Image {
+        let mut image = Image::new(self.width, self.height);
+
+        for y in 0..self.height {
+            for node in (0..self.width).filter_map(|x| self.get_node(x as i32, y as i32)) {
+                image.set(node.x, node.y, grey(node.cost, self.wrap));
+            }
+        }
+        for (x, y) in positions(path) {
+            image.set(x, y, RED);
+        }
+        image
+    }
+
+    fn index(&self, node: &Node) -> usize {
+        self.index_of((node.x, node.y))
+    }
+
+    fn index_of(&self, (x, y): (usize, usize)) -> usize {
+        y * self.width + x
+    }
+
+    fn position(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+
+    // follows the directions back from the goal, stepping the opposite way each time
+    fn reconstruct_path(&self, came_from: &[u8], goal: usize, risk: u32) -> Path {
+        let mut positions = vec![self.position(goal)];
+        let mut index = goal;
+        while came_from[index] != NO_DIRECTION {
+            let (dx, dy) = ORTHOGONAL[came_from[index] as usize - 1];
+            let (x, y) = self.position(index);
+            match offset_within(x, y, -dx, -dy, self.width, self.height) {
+                Some(previous) => positions.push(previous),
+                None => break,
+            }
+            index = self.index_of(positions[positions.len() - 1]);
+        }
+
+        let nodes = positions
+            .into_iter()
+            .rev()
+            .filter_map(|(x, y)| self.get_node(x as i32, y as i32))
+            .collect();
+        Path { nodes, risk }
+    }
+}
+
+// came_from holds no direction for the start and positions not reached yet
+const NO_DIRECTION: u8 = 0;
+
+// What the search keeps for every position of the tiled cave: the lowest risk found to it so far,
+// and which way it was entered, as 1 more than the index into ORTHOGONAL. That's 5 bytes a
+// position, so a 50x50 tiling of the real input stays well within memory.
+struct SearchState {
+    came_from: Vec<u8>,
+    costs: Vec<u32>,
+}
+
+impl SearchState {
+    fn new(size: usize) -> Self {
+        Self {
+            came_from: vec![NO_DIRECTION; size],
+            costs: vec![u32::MAX; size],
+        }
+    }
+
+    #[cfg(test)]
+    fn footprint(&self) -> usize {
+        self.came_from.capacity() * std::mem::size_of::<u8>()
+            + self.costs.capacity() * std::mem::size_of::<u32>()
+    }
+}
+
+// the nodes from start to goal, the risk doesn't count the start as it's never entered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    pub nodes: Vec<Node>,
+    pub risk: u32,
+}
+
+impl Path {
+    // every position along the path with the risk of entering it and the total so far
+    pub fn steps(&self) -> Vec<Step> {
+        let mut total = 0;
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| {
+                let risk = if i == 0 { 0 } else { node.cost };
+                total += risk;
+                Step {
+                    x: node.x,
+                    y: node.y,
+                    risk,
+                    total,
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub x: usize,
+    pub y: usize,
+    pub risk: u32,
+    pub total: u32,
+}
+
+// A guess at the risk left between a node and the goal, which must never be more than the real
+// risk or the search can settle on a worse path
+pub trait Heuristic {
+    fn estimate(&self, node: &Node, goal: &Node) -> u32;
+}
+
+// no guess at all, which turns A* into Dijkstra
+pub struct NoHeuristic;
+
+impl Heuristic for NoHeuristic {
+    fn estimate(&self, _node: &Node, _goal: &Node) -> u32 {
+        0
+    }
+}
+
+// every step costs at least 1, so the distance on the grid is a safe guess
+pub struct Manhattan;
+
+impl Heuristic for Manhattan {
+    fn estimate(&self, node: &Node, goal: &Node) -> u32 {
+        estimate_cost(node, goal)
+    }
+}
+
+impl<F> Heuristic for F
+where
+    F: Fn(&Node, &Node) -> u32,
+{
+    fn estimate(&self, node: &Node, goal: &Node) -> u32 {
+        self(node, goal)
+    }
+}
+
+pub fn parse(input: &str) -> Result<RiskMap> {
+    Ok(RiskMap {
+        // a risk of 0 would make entering a position free, which the path search can't handle
+        risks: Grid::parse_chars(input, "risk levels", |c| {
+            match parse_digit(c, "a risk level")? {
+                0 => Err(AocError::parse("a risk level from 1 to 9", c.to_string())),
+                risk => Ok(risk),
+            }
+        })?,
+    })
+}
+
+pub fn part1(risk_map: &RiskMap) -> Result<u32> {
+    lowest_total_risk(risk_map, 1, RISK_WRAP)
+}
+
+pub fn part2(risk_map: &RiskMap) -> Result<u32> {
+    lowest_total_risk(risk_map, PART2_TILES, RISK_WRAP)
+}
+
+// the lowest risk from the top left to the bottom right of the risk map tiled tiles times in each
+// direction
+pub fn lowest_total_risk(risk_map: &RiskMap, tiles: u32, wrap: u32) -> Result<u32> {
+    lowest_risk_path(risk_map, tiles, wrap).map(|path| path.risk)
+}
+
+pub fn lowest_risk_path(risk_map: &RiskMap, tiles: u32, wrap: u32) -> Result<Path> {
+    let graph = build_graph(risk_map, tiles, wrap)?;
+    let (start, goal) = graph.corners();
+
+    graph
+        .a_star(start, goal)
+        .ok_or_else(|| AocError::unsolvable("there's no path through the cave"))
+}
+
+// draw the cave for the given part with its lowest risk path
+pub fn render(risk_map: &RiskMap, part: u8, format: RenderFormat) -> Result<Vec<u8>> {
+    let tiles = if part == 2 { PART2_TILES } else { 1 };
+    let graph = build_graph(risk_map, tiles, RISK_WRAP)?;
+    let path = lowest_risk_path(risk_map, tiles, RISK_WRAP)?;
+
+    Ok(match format {
+        RenderFormat::Ansi => format!(
+            "{}Lowest total risk: {} over {} steps\n",
+            graph.render_ansi(&path),
+            path.risk,
+            path.nodes.len() - 1
+        )
+        .into_bytes(),
+        RenderFormat::Ppm => graph.render_image(&path).to_ppm(),
+        RenderFormat::Dot => return Err(AocError::unsolvable("day 15 is a map, not a graph")),
+    })
+}
+
+fn positions(path: &Path) -> HashSet<(usize, usize)> {
+    path.nodes.iter().map(|node| (node.x, node.y)).collect()
+}
+
+// the risk map repeated tiles times across and down, with risks above wrap going back round to 1
+pub fn build_graph(risk_map: &RiskMap, tiles: u32, wrap: u32) -> Result<Graph<'_>> {
+    if tiles == 0 {
+        return Err(AocError::malformed("the cave needs at least one tile"));
+    }
+    if wrap == 0 {
+        return Err(AocError::malformed("risk levels can't wrap at 0"));
+    }
+
+    let (width, height) = (
+        risk_map.risks.width() * tiles as usize,
+        risk_map.risks.height() * tiles as usize,
+    );
+    debug!("Map built, size: {}x{}", width, height);
+
+    Ok(Graph {
+        risk_map,
+        wrap,
+        width,
+        height,
+    })
+}
+
+fn estimate_cost(node: &Node, goal: &Node) -> u32 {
+    (node.x.abs_diff(goal.x) + node.y.abs_diff(goal.y)) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> String {
+        let test_data: Vec<&str> = vec![
+            "1163751742",
+            "1381373672",
+            "2136511328",
+            "3694931569",
+            "7463417111",
+            "1319128137",
+            "1359912421",
+            "3125421639",
+            "1293138521",
+            "2311944581",
+        ];
+        test_data.join("\n")
+    }
+
+    #[test]
+    fn part1_example1() -> Result<()> {
+        assert_eq!(part1(&parse(&example())?)?, 40);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_example2() -> Result<()> {
+        assert_eq!(part1(&parse("11199\n99199\n11199\n19999\n11111")?)?, 12);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example1() -> Result<()> {
+        assert_eq!(part2(&parse(&example())?)?, 315);
+        Ok(())
+    }
+
+    #[test]
+    fn dijkstra_matches_a_star() -> Result<()> {
+        let risk_map = parse(&example())?;
+        let graph = build_graph(&risk_map, PART2_TILES, RISK_WRAP)?;
+        let (start, goal) = graph.corners();
+
+        let dijkstra = graph.dijkstra(start, goal);
+        assert_eq!(dijkstra.as_ref().map(|path| path.risk), Some(315));
+        assert_eq!(
+            graph.a_star(start, goal).map(|path| path.risk),
+            dijkstra.map(|path| path.risk)
+        );
+
+        // any admissible heuristic finds the same risk
+        let half_way = |node: &Node, goal: &Node| estimate_cost(node, goal) / 2;
+        assert_eq!(
+            graph.search(start, goal, &half_way).map(|path| path.risk),
+            Some(315)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn start_and_goal() -> Result<()> {
+        let risk_map = parse("11199\n99199\n11199\n19999\n11111")?;
+        let graph = build_graph(&risk_map, 1, RISK_WRAP)?;
+
+        let path = graph.a_star((4, 0), (0, 2));
+        assert_eq!(path.as_ref().map(|path| path.risk), Some(14));
+        assert_eq!(
+            path.map(|path| (path.nodes[0].x, path.nodes[0].y)),
+            Some((4, 0))
+        );
+
+        // going backwards enters the top left corner rather than the bottom right
+        assert_eq!(
+            graph.dijkstra((4, 4), (0, 0)).map(|path| path.risk),
+            Some(12)
+        );
+        assert_eq!(
+            graph.a_star((0, 0), (0, 0)).map(|path| path.nodes.len()),
+            Some(1)
+        );
+        assert_eq!(graph.a_star((0, 0), (5, 0)), None);
+        Ok(())
+    }
+
+    #[test]
+    fn path_steps() -> Result<()> {
+        let path = lowest_risk_path(&parse("19\n11")?, 1, RISK_WRAP)?;
+
+        assert_eq!(
+            path.steps(),
+            vec![
+                Step {
+                    x: 0,
+                    y: 0,
+                    risk: 0,
+                    total: 0
+                },
+                Step {
+                    x: 0,
+                    y: 1,
+                    risk: 1,
+                    total: 1
+                },
+                Step {
+                    x: 1,
+                    y: 1,
+                    risk: 1,
+                    total: 2
+                },
+            ]
+        );
+        assert_eq!(path.risk, 2);
+
+        let path = lowest_risk_path(&parse(&example())?, PART2_TILES, RISK_WRAP)?;
+        assert_eq!(path.steps().last().map(|step| step.total), Some(315));
+        Ok(())
+    }
+
+    #[test]
+    fn render_path() -> Result<()> {
+        let risk_map = parse("19\n11")?;
+
+        let rendered =
+            String::from_utf8(render(&risk_map, 1, RenderFormat::Ansi)?).unwrap_or_default();
+        assert_eq!(
+            rendered,
+            format!(
+                "{}9\n{}{}\nLowest total risk: 2 over 2 steps\n",
+                highlight(1),
+                highlight(1),
+                highlight(1)
+            )
+        );
+
+        let graph = build_graph(&risk_map, 1, RISK_WRAP)?;
+        let image = graph.render_image(&lowest_risk_path(&risk_map, 1, RISK_WRAP)?);
+        assert_eq!(image.get(0, 0), Some(RED));
+        assert_eq!(image.get(1, 0), Some([255; 3]));
+        Ok(())
+    }
+
+    #[test]
+    fn tiled_risks() -> Result<()> {
+        let risk_map = parse("8")?;
+        let graph = build_graph(&risk_map, 50, RISK_WRAP)?;
+        let risk = |x, y| graph.get_node(x, y).map(|node| node.cost);
+
+        assert_eq!((graph.width(), graph.height()), (50, 50));
+        assert_eq!(
+            [risk(0, 0), risk(1, 0), risk(1, 1), risk(2, 0), risk(49, 49)],
+            [Some(8), Some(9), Some(1), Some(1), Some(7)]
+        );
+        assert_eq!(risk(50, 0), None);
+
+        let graph = build_graph(&risk_map, 3, 4)?;
+        assert_eq!(
+            [0, 1, 2].map(|x| graph.get_node(x, 0).map(|node| node.cost)),
+            [Some(4), Some(1), Some(2)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn search_footprint() {
+        // a 100x100 map tiled 5 times each way, 50 times would need 125MB
+        let size = 500 * 500;
+        assert_eq!(SearchState::new(size).footprint(), 5 * size);
+    }
+
+    #[test]
+    fn wide_tiling() -> Result<()> {
+        assert_eq!(lowest_total_risk(&parse(&example())?, 1, RISK_WRAP)?, 40);
+        assert_eq!(lowest_total_risk(&parse("1")?, 3, RISK_WRAP)?, 14);
+        assert_eq!(lowest_total_risk(&parse("1")?, 3, 2)?, 6);
+        assert!(matches!(
+            lowest_total_risk(&parse("1")?, 0, RISK_WRAP),
+            Err(AocError::MalformedInput(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn bad_risk_levels() {
+        assert_eq!(
+            parse("116\n1?8").err(),
+            Some(AocError::parse("a risk level", "?").at_line(2).at_column(2)),
+        );
+        assert_eq!(
+            parse("116\n13").err(),
+            Some(AocError::parse("a row of 3 risk levels", "13").at_line(2)),
+        );
+        assert_eq!(
+            parse("116\n108").err(),
+            Some(
+                AocError::parse("a risk level from 1 to 9", "0")
+                    .at_line(2)
+                    .at_column(2)
+            ),
+        );
+    }
+}
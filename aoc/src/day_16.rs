@@ -0,0 +1,156 @@
+use crate::bits::{self, Packet};
+use crate::error::AocError;
+use crate::solution::{Answer, Result, Solution};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Vec<Packet>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
+
+// the packet from each transmission, one to a line
+pub fn parse(input: &str) -> Result<Vec<Packet>> {
+    let transmissions = bits::decode_stream(input)?;
+    if transmissions.is_empty() {
+        return Err(AocError::malformed("there are no transmissions"));
+    }
+
+    Ok(transmissions
+        .into_iter()
+        .map(|transmission| transmission.packet)
+        .collect())
+}
+
+pub fn part1(packets: &[Packet]) -> u64 {
+    packets.iter().map(Packet::version_sum).sum()
+}
+
+pub fn part2(packets: &[Packet]) -> Result<u64> {
+    packets.iter().try_fold(0u64, |total, packet| {
+        total
+            .checked_add(packet.value()?)
+            .ok_or_else(|| AocError::unsolvable("the transmissions' values add up to too much"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn part1_example1() -> Result<()> {
+        assert_eq!(part1(&parse("8A004A801A8002F478")?), 16);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_example2() -> Result<()> {
+        assert_eq!(part1(&parse("620080001611562C8802118E34")?), 12);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_example3() -> Result<()> {
+        assert_eq!(part1(&parse("C0015000016115A2E0802F182340")?), 23);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_example4() -> Result<()> {
+        assert_eq!(part1(&parse("A0016C880162017C3686B18A3D4780")?), 31);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_sum() -> Result<()> {
+        assert_eq!(part2(&parse("C200B40A82")?)?, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_product() -> Result<()> {
+        assert_eq!(part2(&parse("04005AC33890")?)?, 54);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_min() -> Result<()> {
+        assert_eq!(part2(&parse("880086C3E88112")?)?, 7);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_max() -> Result<()> {
+        assert_eq!(part2(&parse("CE00C43D881120")?)?, 9);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_less() -> Result<()> {
+        assert_eq!(part2(&parse("D8005AC2A8F0")?)?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_greater() -> Result<()> {
+        assert_eq!(part2(&parse("F600BC2D8F")?)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_equal() -> Result<()> {
+        assert_eq!(part2(&parse("9C005AC2F8F0")?)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_nested_operations() -> Result<()> {
+        assert_eq!(part2(&parse("9C0141080250320F1802104A08")?)?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn several_transmissions() -> Result<()> {
+        assert_eq!(part1(&parse("8A004A801A8002F478\nC200B40A82\n")?), 16 + 14);
+        assert_eq!(part2(&parse("C200B40A82\n04005AC33890")?)?, 3 + 54);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_hex() {
+        assert_eq!(
+            parse("8A0G4A").err(),
+            Some(
+                AocError::parse("a hexadecimal digit", "G")
+                    .at_line(1)
+                    .at_column(4)
+            ),
+        );
+    }
+
+    #[test]
+    fn no_transmissions() {
+        for input in ["", "\n", "  \n\n"] {
+            assert_eq!(
+                parse(input).err(),
+                Some(AocError::malformed("there are no transmissions"))
+            );
+        }
+    }
+
+    #[test]
+    fn truncated_transmission() {
+        assert!(matches!(parse("8A004A"), Err(AocError::MalformedInput(_))));
+    }
+}
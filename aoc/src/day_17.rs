@@ -0,0 +1,223 @@
+use std::{cmp::Ordering, collections::HashSet, str::FromStr};
+
+use crate::error::{parse_number, AocError};
+use crate::solution::{Answer, Result, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Rect;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Rect> {
+    let line = input
+        .lines()
+        .next()
+        .ok_or_else(|| AocError::parse("a target area", ""))?;
+    Rect::from_str(line.trim()).map_err(|err| err.at_line(1))
+}
+
+// highest y position reached by any shot that hits the target
+pub fn part1(target: &Rect) -> i32 {
+    let mut best_max_y = 0;
+
+    for y_vel in 0..200 {
+        for x_vel in 0..target.tr.0 / 2 {
+            let (hit, max_y) = fire(x_vel, y_vel, target);
+            if hit && max_y > best_max_y {
+                best_max_y = max_y;
+            }
+        }
+    }
+    best_max_y
+}
+
+// number of distinct initial velocities that hit the target
+pub fn part2(target: &Rect) -> usize {
+    let mut hits = HashSet::new();
+
+    for y_vel in target.br.1..200 {
+        for x_vel in 0..=target.tr.0 {
+            let (hit, _) = fire(x_vel, y_vel, target);
+            if hit {
+                hits.insert((x_vel, y_vel));
+            }
+        }
+    }
+    hits.len()
+}
+
+pub struct Point(pub i32, pub i32);
+
+pub struct Rect {
+    tl: Point,
+    tr: Point,
+    bl: Point,
+    br: Point,
+}
+
+impl Rect {
+    pub fn new(tl: Point, tr: Point, bl: Point, br: Point) -> Self {
+        Self { tl, tr, bl, br }
+    }
+
+    fn hit(&self, loc: &Point) -> bool {
+        loc.0 <= self.tr.0 && loc.0 >= self.tl.0 && loc.1 <= self.tl.1 && loc.1 >= self.bl.1
+    }
+
+    fn no_possible_hit(&self, loc: &Point) -> bool {
+        loc.0 > self.tr.0 || loc.1 < self.bl.1
+    }
+}
+
+impl FromStr for Rect {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        // target area: x=20..30, y=-10..-5
+        const PREFIX: &str = "target area: ";
+        let (x_range, y_range) = s
+            .strip_prefix(PREFIX)
+            .and_then(|ranges| ranges.split_once(", "))
+            .ok_or_else(|| AocError::parse("a target area like x=20..30, y=-10..-5", s))?;
+
+        let (x_min, x_max) =
+            parse_range(x_range, 'x').map_err(|err| err.at_column(PREFIX.len() + 1))?;
+        let (y_min, y_max) = parse_range(y_range, 'y')
+            .map_err(|err| err.at_column(PREFIX.len() + x_range.len() + 3))?;
+
+        // the probe is fired from the origin, so only targets ahead and below can be searched
+        if x_min <= 0 || y_max >= 0 {
+            return Err(AocError::malformed(format!(
+                "the target area x={}..{}, y={}..{} isn't ahead of and below the probe",
+                x_min, x_max, y_min, y_max
+            )));
+        }
+
+        Ok(Self::new(
+            Point(x_min, y_max),
+            Point(x_max, y_max),
+            Point(x_min, y_min),
+            Point(x_max, y_min),
+        ))
+    }
+}
+
+fn parse_range(s: &str, axis: char) -> Result<(i32, i32)> {
+    // x=20..30
+    let (start, end) = s
+        .strip_prefix(axis)
+        .and_then(|range| range.strip_prefix('='))
+        .and_then(|range| range.split_once(".."))
+        .ok_or_else(|| AocError::parse(format!("a range like {}=20..30", axis), s))?;
+
+    let (start, end) = (
+        parse_number(start, "the start of a range").map_err(|err| err.at_column(3))?,
+        parse_number(end, "the end of a range").map_err(|err| err.at_column(start.len() + 5))?,
+    );
+    if start > end {
+        return Err(AocError::parse(
+            format!("a range from low to high like {}=20..30", axis),
+            s,
+        ));
+    }
+    Ok((start, end))
+}
+
+fn fire(initial_x_vel: i32, initial_y_vel: i32, target: &Rect) -> (bool, i32) {
+    let mut loc = Point(0, 0);
+    let mut cur_x_vel = initial_x_vel;
+    let mut cur_y_vel = initial_y_vel;
+
+    let mut max_y = 0;
+    let mut hit = false;
+    while !target.no_possible_hit(&loc) && !hit {
+        loc.0 += cur_x_vel;
+        loc.1 += cur_y_vel;
+
+        if loc.1 > max_y {
+            max_y = loc.1;
+        }
+
+        match cur_x_vel.cmp(&0) {
+            Ordering::Less => cur_x_vel += 1,
+            Ordering::Equal => (),
+            Ordering::Greater => cur_x_vel -= 1,
+        }
+        cur_y_vel -= 1;
+
+        hit = target.hit(&loc);
+    }
+
+    (hit, max_y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn part1_example1() {
+        let target = Rect::new(Point(20, -5), Point(30, -5), Point(20, -10), Point(30, -10));
+        assert_eq!(part1(&target), 45);
+    }
+
+    #[test]
+    fn part2_example1() {
+        let target = Rect::new(Point(20, -5), Point(30, -5), Point(20, -10), Point(30, -10));
+        assert_eq!(part2(&target), 112);
+    }
+
+    #[test]
+    fn parse_target() -> Result<()> {
+        let target = parse("target area: x=20..30, y=-10..-5")?;
+        assert_eq!(part1(&target), 45);
+        assert_eq!(part2(&target), 112);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_target() {
+        assert_eq!(
+            parse("target area: x=20..30, y=-10..-x").err(),
+            Some(
+                AocError::parse("the end of a range", "-x")
+                    .at_line(1)
+                    .at_column(31)
+            ),
+        );
+        assert_eq!(
+            parse("target area: y=20..30, y=-10..-5").err(),
+            Some(
+                AocError::parse("a range like x=20..30", "y=20..30")
+                    .at_line(1)
+                    .at_column(14)
+            ),
+        );
+        assert_eq!(
+            parse("target area: x=20..30, y=-5..-10").err(),
+            Some(
+                AocError::parse("a range from low to high like y=20..30", "y=-5..-10")
+                    .at_line(1)
+                    .at_column(24)
+            ),
+        );
+        assert_eq!(
+            parse("target area: x=-30..-20, y=-10..-5").err(),
+            Some(AocError::malformed(
+                "the target area x=-30..-20, y=-10..-5 isn't ahead of and below the probe"
+            )),
+        );
+    }
+}
@@ -1,42 +1,85 @@
-use std::{error::Error, fmt::Display, fs, ops::Add, str::FromStr};
+use std::{fmt::Display, ops::Add, str::FromStr};
 
-type Result<T> = std::result::Result<T, Box<dyn Error>>;
+use crate::error::{numbered_lines, AocError};
+use crate::solution::{Answer, Result, Solution};
 
-const INPUT_PATH: &str = "input.txt";
+pub struct Day18;
 
-fn main() -> Result<()> {
-    let raw_input = fs::read_to_string(INPUT_PATH)?;
-    let input: Vec<&str> = raw_input.lines().map(|line| line.trim()).collect();
+impl Solution for Day18 {
+    type Input = Vec<Node>;
 
-    let output = compute(&input)?;
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
 
-    println!("Puzzle output: {}", output);
-    Ok(())
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
 }
 
-fn compute(input: &[&str]) -> Result<u32> {
+pub fn parse(input: &str) -> Result<Vec<Node>> {
     let mut numbers = Vec::new();
-    for line in input {
-        numbers.push(Node::from_str(line)?);
+    for (line_number, line) in numbered_lines(input) {
+        if line.is_empty() {
+            continue;
+        }
+        numbers.push(Node::from_str(line).map_err(|err| err.at_line(line_number))?);
     }
+    Ok(numbers)
+}
 
+// magnitude of the sum of every number in order
+pub fn part1(numbers: &[Node]) -> Result<u32> {
     // reduce numbers by adding
-    let mut iter = numbers.into_iter();
-    let mut sum = iter.next().unwrap();
+    let mut iter = numbers.iter();
+    let mut sum = iter
+        .next()
+        .ok_or_else(|| AocError::unsolvable("there are no snailfish numbers to add"))?
+        .clone();
     for node in iter {
-        sum = sum + node;
+        sum = &sum + node;
     }
+
     Ok(sum.magnitude())
 }
 
+// largest magnitude from adding any two of the numbers
+pub fn part2(numbers: &[Node]) -> u32 {
+    // need to find max value from summing x + y numbers for each x and y in the list
+    // they aren't commutative though, so x + y != y + x
+    let mut max_magnitude = 0;
+
+    for number_1 in numbers {
+        for number_2 in numbers {
+            let sum_xy = number_1 + number_2;
+            let mag_xy = sum_xy.magnitude();
+            if mag_xy > max_magnitude {
+                max_magnitude = mag_xy;
+            }
+
+            let sum_yx = number_2 + number_1;
+            let mag_yx = sum_yx.magnitude();
+            if mag_yx > max_magnitude {
+                max_magnitude = mag_yx;
+            }
+        }
+    }
+
+    max_magnitude
+}
+
 #[derive(Debug)]
 enum Side {
     Lhs,
     Rhs,
 }
 
-#[derive(Debug)]
-struct Node {
+#[derive(Debug, Clone)]
+pub struct Node {
     lhs: Option<Box<Node>>,
     rhs: Option<Box<Node>>,
     value: Option<u32>,
@@ -59,7 +102,7 @@ impl Node {
         }
     }
 
-    fn magnitude(&self) -> u32 {
+    pub fn magnitude(&self) -> u32 {
         match self.value {
             Some(n) => n,
             None => {
@@ -218,13 +261,13 @@ impl Display for Node {
     }
 }
 
-impl Add for Node {
+impl Add for &Node {
     type Output = Node;
 
     fn add(self, rhs: Self) -> Self::Output {
         let mut new_node = Node::new();
-        new_node.lhs = Some(Box::new(self));
-        new_node.rhs = Some(Box::new(rhs));
+        new_node.lhs = Some(Box::new(self.clone()));
+        new_node.rhs = Some(Box::new(rhs.clone()));
 
         new_node.reduce();
 
@@ -233,35 +276,39 @@ impl Add for Node {
 }
 
 impl FromStr for Node {
-    type Err = String;
+    type Err = AocError;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         // for each [ make a new Node
         // for each number make a new node with value
         let mut root_node = None;
 
-        let mut stack = Vec::new();
-        for val in s.chars() {
+        let mut stack: Vec<Node> = Vec::new();
+        for (column, val) in s.chars().enumerate() {
+            let error = |expected: &str| {
+                Err(AocError::parse(expected, val.to_string()).at_column(column + 1))
+            };
+
+            if root_node.is_some() {
+                return error("the end of the number");
+            }
+
             match val {
                 // push a new blank pair onto the stack
                 '[' => {
                     stack.push(Node::new());
                 }
-                '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => {
-                    let value = val
-                        .to_digit(10)
-                        .ok_or_else(|| format!("Error converting char {} to digit", val))?;
-
-                    let mut current_node =
-                        stack.pop().expect("Found a digit but the stack was empty");
-
-                    if current_node.lhs.is_none() {
-                        current_node.lhs = Some(Box::new(Node::leaf(value)));
-                    } else {
-                        current_node.rhs = Some(Box::new(Node::leaf(value)));
-                    }
+                '0'..='9' => {
+                    let value = val.to_digit(10).unwrap_or_default();
 
-                    stack.push(current_node);
+                    match stack.last_mut() {
+                        Some(current_node) => {
+                            if !current_node.add_child(Node::leaf(value)) {
+                                return error("a ]");
+                            }
+                        }
+                        None => return error("a ["),
+                    }
                 }
                 ']' => {
                     // we need to link this pair to the parent pair
@@ -271,28 +318,44 @@ impl FromStr for Node {
                     //                shouldn't be added to
                     // unless we don't add this to the stack once done, and leave it linked only
                     // via the tree
-                    let current_node = stack.pop().expect("Malformed number");
-                    match stack.pop() {
+                    let current_node = match stack.pop() {
+                        Some(node) if node.rhs.is_some() => node,
+                        Some(_) => return error("a number or a ["),
+                        None => return error("a ["),
+                    };
+                    match stack.last_mut() {
                         None => {
                             // stack is empty, the current node is the only node
                             // can add the current node to the number as the root
                             root_node = Some(current_node);
                         }
-                        Some(mut node) => {
-                            if node.lhs.is_none() {
-                                node.lhs = Some(Box::new(current_node));
-                            } else if node.rhs.is_none() {
-                                node.rhs = Some(Box::new(current_node));
+                        Some(node) => {
+                            if !node.add_child(current_node) {
+                                return error("a ]");
                             }
-                            stack.push(node);
                         }
                     }
                 }
-
-                _ => (),
+                ',' | ' ' => (),
+                _ => return error("a snailfish number"),
             }
         }
-        Ok(root_node.unwrap())
+
+        root_node.ok_or_else(|| AocError::parse("a ]", "").at_column(s.chars().count() + 1))
+    }
+}
+
+impl Node {
+    // fill in the next empty side of a pair, false if both sides are already taken
+    fn add_child(&mut self, child: Node) -> bool {
+        if self.lhs.is_none() {
+            self.lhs = Some(Box::new(child));
+        } else if self.rhs.is_none() {
+            self.rhs = Some(Box::new(child));
+        } else {
+            return false;
+        }
+        true
     }
 }
 
@@ -301,7 +364,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn example1() -> Result<()> {
+    fn part1_example1() -> Result<()> {
         let test_data = vec![
             "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
             "[[[5,[2,8]],4],[5,[[9,9],0]]]",
@@ -315,12 +378,12 @@ mod tests {
             "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
         ];
 
-        assert_eq!(compute(&test_data)?, 4140);
+        assert_eq!(part1(&parse(&test_data.join("\n"))?)?, 4140);
         Ok(())
     }
 
     #[test]
-    fn example2() -> Result<()> {
+    fn part1_example2() -> Result<()> {
         let test_data = vec![
             "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]",
             "[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]",
@@ -334,7 +397,7 @@ mod tests {
             "[[[[4,2],2],6],[8,7]]",
         ];
 
-        assert_eq!(compute(&test_data)?, 3488);
+        assert_eq!(part1(&parse(&test_data.join("\n"))?)?, 3488);
         Ok(())
     }
 
@@ -367,7 +430,7 @@ mod tests {
         let lhs = Node::from_str("[1,2]")?;
         let rhs = Node::from_str("[3,4]")?;
 
-        assert_eq!(format!("{}", lhs + rhs), "[[1,2],[3,4]]");
+        assert_eq!(format!("{}", &lhs + &rhs), "[[1,2],[3,4]]");
         Ok(())
     }
 
@@ -376,7 +439,7 @@ mod tests {
         let lhs = Node::from_str("[1,1]")?;
         let rhs = Node::from_str("[[[[4,4],3,],2],1]")?;
 
-        assert_eq!(format!("{}", lhs + rhs), "[[1,5],[[[0,7],2],1]]");
+        assert_eq!(format!("{}", &lhs + &rhs), "[[1,5],[[[0,7],2],1]]");
         Ok(())
     }
 
@@ -431,7 +494,7 @@ mod tests {
         let rhs = Node::from_str("[1,1]")?;
 
         assert_eq!(
-            format!("{}", lhs + rhs),
+            format!("{}", &lhs + &rhs),
             "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"
         );
         Ok(())
@@ -444,4 +507,44 @@ mod tests {
         assert_eq!(node.magnitude(), 143);
         Ok(())
     }
+
+    #[test]
+    fn part2_example1() -> Result<()> {
+        let test_data = vec![
+            "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
+            "[[[5,[2,8]],4],[5,[[9,9],0]]]",
+            "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
+            "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
+            "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
+            "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
+            "[[[[5,4],[7,7]],8],[[8,3],8]]",
+            "[[9,3],[[9,9],[6,[4,9]]]]",
+            "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
+            "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+        ];
+
+        assert_eq!(part2(&parse(&test_data.join("\n"))?), 3993);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_numbers() {
+        assert_eq!(
+            parse("[1,2]\n[1,2,3]").err(),
+            Some(AocError::parse("a ]", "3").at_line(2).at_column(6)),
+        );
+        assert_eq!(
+            Node::from_str("[[1],2]").err(),
+            Some(AocError::parse("a number or a [", "]").at_column(4)),
+        );
+        assert_eq!(
+            Node::from_str("[1,[2,3]").err(),
+            Some(AocError::parse("a ]", "").at_column(9)),
+        );
+        assert_eq!(
+            Node::from_str("[1,2]]").err(),
+            Some(AocError::parse("the end of the number", "]").at_column(6)),
+        );
+        assert!(Node::from_str("[1,x]").is_err());
+    }
 }
@@ -1,25 +1,35 @@
-use std::{collections::HashMap, error::Error, fs, str::FromStr};
+// part 1 is still a work in progress, most of the scanner types aren't wired up yet
+#![allow(dead_code)]
 
-type Result<T> = std::result::Result<T, Box<dyn Error>>;
+use std::str::FromStr;
 
-const INPUT_PATH: &str = "input.txt";
+use crate::error::{numbered_lines, parse_number, AocError};
+use crate::log::debug;
+use crate::solution::{Answer, Result, Solution};
 
-fn main() -> Result<()> {
-    let raw_input = fs::read_to_string(INPUT_PATH)?;
-    let input: Vec<&str> = raw_input.lines().map(|line| line.trim()).collect();
+pub struct Day19;
 
-    let output = compute(&input)?;
+impl Solution for Day19 {
+    type Input = Vec<Scanner>;
 
-    println!("Puzzle output: {}", output);
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Answer> {
+        Err(AocError::unsolvable("day 19 part 2 hasn't been solved yet"))
+    }
 }
 
-fn compute(input: &[&str]) -> Result<u32> {
-    let scanners = create_scanners(input)?;
-    println!("Number of scanners: {}", scanners.len());
+pub fn part1(scanners: &[Scanner]) -> u32 {
+    debug!("Number of scanners: {}", scanners.len());
 
     // all of our axis rotations
-    let rotations = vec![
+    let _rotations = [
         Rotation {
             x_rot: Sign::Pos,
             y_rot: Sign::Pos,
@@ -68,16 +78,19 @@ fn compute(input: &[&str]) -> Result<u32> {
     // These are found by comparing each rotated beacon set with the base one and seeing the beacon
     // offsets match up
 
-    Ok(0)
+    0
 }
 
-fn create_scanners(input: &[&str]) -> Result<Vec<Scanner>> {
+pub fn parse(input: &str) -> Result<Vec<Scanner>> {
     let mut scanners = Vec::new();
     let mut tmp_beacons = Vec::new();
     let mut label = String::new();
 
-    for line in input {
+    for (line_number, line) in numbered_lines(input) {
         if line.is_empty() {
+            if label.is_empty() && tmp_beacons.is_empty() {
+                continue;
+            }
             scanners.push(Scanner::with_beacons(label.clone(), tmp_beacons));
             tmp_beacons = Vec::new();
             label.clear();
@@ -86,7 +99,7 @@ fn create_scanners(input: &[&str]) -> Result<Vec<Scanner>> {
             label = line.to_string();
         } else {
             // beacon
-            tmp_beacons.push(Beacon::from_str(line)?);
+            tmp_beacons.push(Beacon::from_str(line).map_err(|err| err.at_line(line_number))?);
         }
     }
 
@@ -99,7 +112,7 @@ fn create_scanners(input: &[&str]) -> Result<Vec<Scanner>> {
 }
 
 #[derive(Debug, Clone)]
-struct Scanner {
+pub struct Scanner {
     label: String,
     beacons: Vec<Beacon>,
 }
@@ -179,16 +192,23 @@ impl Beacon {
 }
 
 impl FromStr for Beacon {
-    type Err = String;
+    type Err = AocError;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         // x,y,z
-        let coords = s
-            .split(',')
-            .map(|val| val.parse::<i32>().map_err(|err| err.to_string()))
-            .collect::<std::result::Result<Vec<i32>, _>>()?;
+        let mut coords = Vec::with_capacity(3);
+        let mut column = 1;
+        for val in s.split(',') {
+            coords.push(
+                parse_number::<i32>(val, "a coordinate").map_err(|err| err.at_column(column))?,
+            );
+            column += val.len() + 1;
+        }
 
-        Ok(Beacon::new(coords[0], coords[1], coords[2]))
+        match coords[..] {
+            [x, y, z] => Ok(Beacon::new(x, y, z)),
+            _ => Err(AocError::parse("a beacon like 404,-588,-901", s)),
+        }
     }
 }
 
@@ -197,8 +217,9 @@ mod tests {
     use super::*;
 
     #[test]
-    fn example1() -> Result<()> {
-        let test_data = vec![
+    #[ignore = "scanner alignment isn't implemented yet"]
+    fn part1_example1() -> Result<()> {
+        let test_data: Vec<&str> = vec![
             "--- scanner 0 ---",
             "404,-588,-901",
             "528,-643,409",
@@ -337,7 +358,19 @@ mod tests {
             "30,-46,-14",
         ];
 
-        assert_eq!(compute(&test_data)?, 1);
+        assert_eq!(part1(&parse(&test_data.join("\n"))?), 1);
         Ok(())
     }
+
+    #[test]
+    fn bad_beacons() {
+        assert_eq!(
+            parse("--- scanner 0 ---\n404,-588,-901\n528,x,409").err(),
+            Some(AocError::parse("a coordinate", "x").at_line(3).at_column(5)),
+        );
+        assert_eq!(
+            parse("--- scanner 0 ---\n404,-588").err(),
+            Some(AocError::parse("a beacon like 404,-588,-901", "404,-588").at_line(2)),
+        );
+    }
 }
@@ -0,0 +1,156 @@
+use std::str::FromStr;
+
+use crate::error::{numbered_lines, parse_number, AocError};
+use crate::log::debug;
+use crate::solution::{Answer, Result, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+impl FromStr for Command {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        // forward 5
+        let (command, value) = s
+            .split_once(' ')
+            .ok_or_else(|| AocError::parse("a command and a distance", s))?;
+
+        let value_column = command.len() + 2;
+        let parsed_value =
+            parse_number::<i32>(value, "a distance").map_err(|err| err.at_column(value_column))?;
+
+        match command {
+            "forward" => Ok(Self::Forward(parsed_value)),
+            "down" => Ok(Self::Down(parsed_value)),
+            "up" => Ok(Self::Up(parsed_value)),
+            _ => Err(AocError::parse("forward, down or up", command).at_column(1)),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Command>> {
+    numbered_lines(input)
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line_number, line)| Command::from_str(line).map_err(|err| err.at_line(line_number)))
+        .collect()
+}
+
+pub fn part1(commands: &[Command]) -> i32 {
+    let mut horizontal_pos = 0;
+    let mut depth = 0;
+
+    for cmd in commands {
+        match cmd {
+            Command::Forward(delta) => horizontal_pos += delta,
+            Command::Down(delta) => depth += delta,
+            Command::Up(delta) => depth -= delta,
+        }
+    }
+    debug!("Horizontal position: {}, depth: {}", horizontal_pos, depth);
+    horizontal_pos * depth
+}
+
+pub fn part2(commands: &[Command]) -> i32 {
+    let mut horizontal_pos = 0;
+    let mut depth = 0;
+    let mut aim = 0;
+
+    for cmd in commands {
+        match cmd {
+            Command::Forward(delta) => {
+                horizontal_pos += delta;
+                depth += aim * delta;
+            }
+            Command::Down(delta) => aim += delta,
+            Command::Up(delta) => aim -= delta,
+        }
+    }
+    debug!("Horizontal position: {}, depth: {}", horizontal_pos, depth);
+    horizontal_pos * depth
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "forward 5
+                down 5
+                forward 8
+                up 3
+                down 8
+                forward 2";
+
+    #[test]
+    fn part1_example1() -> Result<()> {
+        assert_eq!(part1(&parse(EXAMPLE)?), 150);
+        Ok(())
+    }
+    #[test]
+    fn part1_basic_test() -> Result<()> {
+        assert_eq!(part1(&parse("up 1\nforward 1")?), -1);
+        Ok(())
+    }
+    #[test]
+    fn part1_no_input() -> Result<()> {
+        // start at 0, 0
+        // anything * 0 = 0
+        assert_eq!(part1(&parse("up 3")?), 0);
+        Ok(())
+    }
+    #[test]
+    fn part2_example1() -> Result<()> {
+        assert_eq!(part2(&parse(EXAMPLE)?), 900);
+        Ok(())
+    }
+    #[test]
+    fn part2_basic_test() -> Result<()> {
+        assert_eq!(part2(&parse("up 1\nforward 1")?), -1);
+        Ok(())
+    }
+    #[test]
+    fn part2_no_input() -> Result<()> {
+        assert_eq!(part2(&parse("up 3")?), 0);
+        Ok(())
+    }
+    #[test]
+    fn parse_bad_command() {
+        assert_eq!(
+            parse("up 1\nsideways 3").err(),
+            Some(
+                AocError::parse("forward, down or up", "sideways")
+                    .at_line(2)
+                    .at_column(1)
+            )
+        );
+    }
+    #[test]
+    fn parse_bad_distance() {
+        assert_eq!(
+            parse("forward x").err(),
+            Some(AocError::parse("a distance", "x").at_line(1).at_column(9))
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use std::fmt::Display;
+
+use crate::error::{numbered_lines, AocError};
+use crate::grid::Grid;
+use crate::log::debug;
+use crate::solution::{Answer, Result, Solution};
+
+pub const PART1_PASSES: usize = 2;
+pub const PART2_PASSES: usize = 50;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Scan;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
+
+// the image enhancement algorithm and the input image it's applied to
+pub struct Scan {
+    pub algorithm: Enhancement,
+    pub image: InputImage,
+}
+
+pub fn parse(input: &str) -> Result<Scan> {
+    let mut lines = numbered_lines(input).filter(|(_, line)| !line.is_empty());
+
+    let (line_number, raw_algorithm) = lines
+        .next()
+        .ok_or_else(|| AocError::parse("an image enhancement algorithm", ""))?;
+    let mut algorithm = Enhancement { data: [0; 512] };
+    if raw_algorithm.len() != algorithm.data.len() {
+        return Err(AocError::parse(
+            "an algorithm of 512 pixels",
+            format!("{} pixels", raw_algorithm.len()),
+        )
+        .at_line(line_number));
+    }
+    for (i, val) in raw_algorithm.chars().enumerate() {
+        algorithm.data[i] =
+            parse_pixel(val).map_err(|err| err.at_line(line_number).at_column(i + 1))?;
+    }
+
+    Ok(Scan {
+        algorithm,
+        image: InputImage::new(Grid::from_lines(lines, "pixels", parse_pixel)?, 0),
+    })
+}
+
+fn parse_pixel(val: char) -> Result<i32> {
+    match val {
+        '#' => Ok(1),
+        '.' => Ok(0),
+        _ => Err(AocError::parse("a # or .", val.to_string())),
+    }
+}
+
+pub fn part1(scan: &Scan) -> Result<i32> {
+    enhance(scan, PART1_PASSES)
+}
+
+pub fn part2(scan: &Scan) -> Result<i32> {
+    enhance(scan, PART2_PASSES)
+}
+
+// number of lit pixels after running the algorithm over the image the given number of times
+pub fn enhance(scan: &Scan, passes: usize) -> Result<i32> {
+    let mut input_image = scan.image.clone();
+    for i in 0..passes {
+        debug!("Starting iteration {}", i);
+        input_image = input_image.new_image_from_algorithm(&scan.algorithm)?;
+    }
+
+    Ok(input_image.count_lit_pixels())
+}
+
+pub struct Enhancement {
+    data: [i32; 512],
+}
+
+// the pixels of the image, with every pixel beyond its edges set to infinite_value
+#[derive(Clone)]
+pub struct InputImage {
+    pixels: Grid<i32>,
+    infinite_value: i32,
+}
+
+// coords, (0,0) is top left
+impl InputImage {
+    pub fn new(pixels: Grid<i32>, infinite_value: i32) -> Self {
+        Self {
+            pixels,
+            infinite_value,
+        }
+    }
+
+    pub fn count_lit_pixels(&self) -> i32 {
+        self.pixels.values().sum()
+    }
+
+    fn get_pixel_value(&self, x: i32, y: i32) -> u16 {
+        // get the 9 values from the data
+        // convert to a binary number
+        // Note: if the value is out of range, assume 0
+
+        // getting (0,0), we need (-1, -1) for top left
+        let px_vals = vec![
+            self.get_data_val_with_offsets(x, y, -1, -1), // top-left
+            self.get_data_val_with_offsets(x, y, 0, -1),  // top-mid
+            self.get_data_val_with_offsets(x, y, 1, -1),  // top-right
+            self.get_data_val_with_offsets(x, y, -1, 0),  // mid-left
+            self.get_data_val_with_offsets(x, y, 0, 0),   // mid-mid
+            self.get_data_val_with_offsets(x, y, 1, 0),   // mid-right
+            self.get_data_val_with_offsets(x, y, -1, 1),  // bot-left
+            self.get_data_val_with_offsets(x, y, 0, 1),   // bot-mid
+            self.get_data_val_with_offsets(x, y, 1, 1),   // bot-right
+        ];
+
+        self.convert_vec_to_binary(&px_vals)
+    }
+    fn convert_vec_to_binary(&self, vec: &[i32]) -> u16 {
+        // convert to a binary digit
+        let mut val = 0;
+        for px_val in vec {
+            match px_val {
+                0 => val <<= 1,
+                1 => {
+                    val <<= 1;
+                    val += 1;
+                }
+                _ => (),
+            }
+        }
+        val
+    }
+
+    fn get_data_val_with_offsets(&self, x: i32, y: i32, x_off: i32, y_off: i32) -> i32 {
+        self.pixels
+            .get_signed((x + x_off) as isize, (y + y_off) as isize)
+            .copied()
+            .unwrap_or(self.infinite_value)
+    }
+
+    // the image grows by a pixel on every side each time, as the infinite background spreads in
+    pub fn new_image_from_algorithm(&self, algo: &Enhancement) -> Result<Self> {
+        let width = self.pixels.width() + 2;
+        let height = self.pixels.height() + 2;
+
+        let mut data = Vec::with_capacity(width * height);
+
+        for y in -1..=self.pixels.height() as i32 {
+            for x in -1..=self.pixels.width() as i32 {
+                let px_value = self.get_pixel_value(x, y);
+
+                let new_px_value = algo.data[px_value as usize];
+
+                data.push(new_px_value);
+            }
+        }
+
+        let new_infinite =
+            algo.data[self.convert_vec_to_binary(&[self.infinite_value; 9]) as usize];
+
+        Ok(Self::new(Grid::new(width, height, data)?, new_infinite))
+    }
+}
+
+impl Display for InputImage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pixels = self.pixels.map(|pixel| if *pixel == 1 { '#' } else { '.' });
+        write!(f, "{}", pixels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> String {
+        let test_data: Vec<&str> = vec![
+"..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#",
+"",
+"#..#.",
+"#....",
+"##..#",
+"..#..",
+"..###",
+        ];
+
+        test_data.join("\n")
+    }
+
+    #[test]
+    fn part1_example1() -> Result<()> {
+        assert_eq!(part1(&parse(&example())?)?, 35);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example1() -> Result<()> {
+        assert_eq!(part2(&parse(&example())?)?, 3351);
+        Ok(())
+    }
+
+    #[test]
+    fn get_algorithm_index_1() -> Result<()> {
+        let sut = InputImage::new(Grid::new(3, 3, vec![1, 1, 1, 1, 1, 1, 1, 1, 1])?, 0);
+
+        assert_eq!(sut.get_pixel_value(1, 1), 511);
+        Ok(())
+    }
+
+    #[test]
+    fn get_algorithm_index_2() -> Result<()> {
+        let sut = InputImage::new(Grid::new(3, 3, vec![1, 0, 0, 0, 0, 0, 0, 0, 1])?, 0);
+
+        assert_eq!(sut.get_pixel_value(1, 1), 257);
+        Ok(())
+    }
+
+    #[test]
+    fn get_algorithm_index_3() -> Result<()> {
+        let sut = InputImage::new(Grid::new(3, 3, vec![1, 1, 1, 1, 1, 1, 1, 1, 1])?, 0);
+
+        assert_eq!(sut.get_pixel_value(0, 0), 27);
+        Ok(())
+    }
+
+    #[test]
+    fn get_algorithm_index_4() -> Result<()> {
+        let sut = InputImage::new(Grid::new(3, 3, vec![1, 1, 1, 1, 1, 1, 1, 1, 1])?, 0);
+
+        assert_eq!(sut.get_pixel_value(2, 2), 432);
+        Ok(())
+    }
+
+    #[test]
+    fn display() -> Result<()> {
+        let scan = parse(&example())?;
+        assert_eq!(
+            scan.image.to_string(),
+            "#..#.\n#....\n##..#\n..#..\n..###\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn bad_pixels() {
+        assert_eq!(
+            parse("#.#").err(),
+            Some(AocError::parse("an algorithm of 512 pixels", "3 pixels").at_line(1)),
+        );
+
+        let algorithm = ".".repeat(512);
+        assert_eq!(
+            parse(&format!("{}\n\n#..\n.x.", algorithm)).err(),
+            Some(AocError::parse("a # or .", "x").at_line(4).at_column(2)),
+        );
+        assert_eq!(
+            parse(&format!("{}\n\n#..\n..", algorithm)).err(),
+            Some(AocError::parse("a row of 3 pixels", "..").at_line(4)),
+        );
+    }
+}
@@ -0,0 +1,335 @@
+use std::collections::HashMap;
+
+use crate::error::{numbered_lines, parse_number, AocError};
+use crate::log::{debug, trace};
+use crate::solution::{Answer, Result, Solution};
+use PlayerId::{Player1, Player2};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = (u8, u8);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input.0, input.1).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input.0, input.1).into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<(u8, u8)> {
+    // Player 1 starting position: 4
+    let mut positions = numbered_lines(input)
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line_number, line)| -> Result<u8> {
+            let (label, position) = line.rsplit_once(": ").ok_or_else(|| {
+                AocError::parse("a line like Player 1 starting position: 4", line)
+                    .at_line(line_number)
+            })?;
+            // the track only has spaces 1 to 10
+            parse_number::<u8>(position, "a position from 1 to 10")
+                .and_then(|position| match position {
+                    1..=10 => Ok(position),
+                    _ => Err(AocError::parse(
+                        "a position from 1 to 10",
+                        position.to_string(),
+                    )),
+                })
+                .map_err(|err| err.at_line(line_number).at_column(label.len() + 3))
+        });
+
+    let player_1_pos = positions
+        .next()
+        .ok_or_else(|| AocError::parse("player 1's starting position", ""))??;
+    let player_2_pos = positions
+        .next()
+        .ok_or_else(|| AocError::parse("player 2's starting position", ""))??;
+
+    Ok((player_1_pos, player_2_pos))
+}
+
+// practice game with the deterministic die
+pub fn part1(player_1_pos: u8, player_2_pos: u8) -> u32 {
+    let mut player_1 = CurrentPlayer::Player1(Player::new(player_1_pos));
+    let mut player_2 = CurrentPlayer::Player2(Player::new(player_2_pos));
+
+    let mut current_player = &mut player_2;
+
+    let mut die = Die::new();
+
+    while !current_player.has_won() {
+        match current_player {
+            CurrentPlayer::Player1(_) => current_player = &mut player_2,
+            CurrentPlayer::Player2(_) => current_player = &mut player_1,
+        }
+        let roll = die.roll_3();
+
+        current_player.move_position(roll);
+    }
+
+    let losing_player_score = if player_1.has_won() {
+        player_2.score()
+    } else {
+        player_1.score()
+    };
+
+    losing_player_score * die.rolls
+}
+
+#[derive(Debug, Copy, Clone)]
+enum CurrentPlayer {
+    Player1(Player),
+    Player2(Player),
+}
+
+impl CurrentPlayer {
+    fn has_won(&self) -> bool {
+        match self {
+            CurrentPlayer::Player1(p) => p.has_won(),
+            CurrentPlayer::Player2(p) => p.has_won(),
+        }
+    }
+
+    fn score(&self) -> u32 {
+        match self {
+            CurrentPlayer::Player1(p) => p.score,
+            CurrentPlayer::Player2(p) => p.score,
+        }
+    }
+
+    fn move_position(&mut self, roll: u16) {
+        match self {
+            CurrentPlayer::Player1(p) => p.move_position(roll),
+            CurrentPlayer::Player2(p) => p.move_position(roll),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Die {
+    current_val: u8,
+    rolls: u32,
+}
+
+impl Die {
+    fn new() -> Self {
+        Self {
+            current_val: 0,
+            rolls: 0,
+        }
+    }
+
+    fn roll(&mut self) -> u8 {
+        self.rolls += 1;
+
+        let mut next_value = self.current_val + 1;
+        if next_value > 100 {
+            next_value = 1;
+        }
+        self.current_val = next_value;
+        next_value
+    }
+
+    fn roll_3(&mut self) -> u16 {
+        let mut roll_3 = 0_u16;
+        roll_3 += self.roll() as u16;
+        roll_3 += self.roll() as u16;
+        roll_3 += self.roll() as u16;
+
+        roll_3
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Player {
+    position: u8,
+    score: u32,
+}
+
+impl Player {
+    fn new(position: u8) -> Self {
+        Self { position, score: 0 }
+    }
+
+    fn move_position(&mut self, roll: u16) {
+        self.position += (roll % 10) as u8;
+        if self.position > 10 {
+            self.position -= 10;
+        }
+
+        self.score += self.position as u32;
+    }
+
+    fn has_won(&self) -> bool {
+        self.score >= 1000
+    }
+}
+
+// number of universes the player who wins most often wins in, with the Dirac die
+pub fn part2(player_1_pos: u8, player_2_pos: u8) -> u64 {
+    let mut new_states: HashMap<GameState, u64> = HashMap::new();
+
+    // mapping of total roll count to the number of states that could result in that number
+    // ie. rolls of 1,1,1 can only happen once, so only one new universe is spawned with that roll
+    // count
+    let die_map = HashMap::from([(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)]);
+
+    new_states.insert(GameState::new(player_1_pos, player_2_pos), 1);
+
+    let mut player_1_wins = 0;
+    let mut player_2_wins = 0;
+
+    while !new_states.is_empty() {
+        let game_states = new_states.clone();
+        new_states.clear();
+
+        for (current_state, universe_total) in game_states.iter() {
+            for (roll, spawned_universes) in &die_map {
+                let mut new_state = *current_state;
+                new_state.next(*roll);
+                let new_universe_count = universe_total * *spawned_universes;
+
+                match new_state.has_winner() {
+                    None => {
+                        // push the game state onto the map with the updated universe count
+                        let universe_count = new_states.entry(new_state).or_insert(0);
+                        *universe_count += new_universe_count;
+                    }
+                    Some(Player1) => {
+                        // increment p1 win count
+                        player_1_wins += new_universe_count;
+                    }
+                    Some(Player2) => {
+                        // increment p2 win count
+                        player_2_wins += new_universe_count;
+                    }
+                };
+            }
+        }
+        trace!("new_states count: {}", new_states.len());
+    }
+    debug!("{} vs {}", player_1_wins, player_2_wins);
+    player_1_wins.max(player_2_wins)
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
+enum PlayerId {
+    Player1,
+    Player2,
+}
+
+#[derive(Hash, PartialEq, Eq, Debug, Copy, Clone)]
+struct GameState {
+    p1: PlayerState,
+    p2: PlayerState,
+    player_turn: PlayerId,
+}
+
+impl GameState {
+    fn new(p1_pos: u8, p2_pos: u8) -> Self {
+        Self {
+            p1: PlayerState::new(p1_pos),
+            p2: PlayerState::new(p2_pos),
+            player_turn: Player1,
+        }
+    }
+
+    fn next(&mut self, roll: u8) {
+        match self.player_turn {
+            Player1 => {
+                self.p1.update(roll);
+                self.player_turn = Player2;
+            }
+            Player2 => {
+                self.p2.update(roll);
+                self.player_turn = Player1;
+            }
+        }
+    }
+
+    fn has_winner(&self) -> Option<PlayerId> {
+        if self.p1.score >= 21 {
+            return Some(Player1);
+        } else if self.p2.score >= 21 {
+            return Some(Player2);
+        }
+        None
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, Debug, Copy, Clone)]
+struct PlayerState {
+    pos: u8,
+    score: u8,
+}
+
+impl PlayerState {
+    fn new(pos: u8) -> Self {
+        Self { pos, score: 0 }
+    }
+
+    fn update(&mut self, roll: u8) {
+        self.pos += roll;
+        if self.pos > 10 {
+            self.pos -= 10;
+        }
+        self.score += self.pos;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example1() {
+        assert_eq!(part1(4, 8), 739785);
+    }
+
+    #[test]
+    fn part2_example1() {
+        assert_eq!(part2(4, 8), 444356092776315);
+    }
+
+    #[test]
+    fn parse_positions() -> Result<()> {
+        let test_data = "Player 1 starting position: 4
+            Player 2 starting position: 8";
+
+        assert_eq!(parse(test_data)?, (4, 8));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_missing_position() {
+        assert!(parse("Player 1 starting position: 4").is_err());
+    }
+
+    #[test]
+    fn parse_bad_position() {
+        assert_eq!(
+            parse("Player 1 starting position: 4\nPlayer 2 starting position: 11").err(),
+            Some(
+                AocError::parse("a position from 1 to 10", "11")
+                    .at_line(2)
+                    .at_column(29)
+            ),
+        );
+        assert_eq!(
+            parse("Player 1 starting position 4").err(),
+            Some(
+                AocError::parse(
+                    "a line like Player 1 starting position: 4",
+                    "Player 1 starting position 4"
+                )
+                .at_line(1)
+            ),
+        );
+    }
+}
@@ -1,25 +1,64 @@
-use std::{error::Error, fs, str::FromStr};
+use std::str::FromStr;
 
-type Result<T> = std::result::Result<T, Box<dyn Error>>;
+use crate::error::{numbered_lines, parse_number, AocError};
+use crate::solution::{Answer, Result, Solution};
 
-const INPUT_PATH: &str = "input.txt";
+// part 1 only considers the cubes in the initialization procedure region
+const INITIALIZATION_RANGE: CoordRange = CoordRange(-50, 50);
 
-fn main() -> Result<()> {
-    let raw_input = fs::read_to_string(INPUT_PATH)?;
-    let input: Vec<&str> = raw_input.lines().map(|line| line.trim()).collect();
+pub struct Day22;
 
-    let output = compute(&input)?;
+impl Solution for Day22 {
+    type Input = Vec<RebootStep>;
 
-    println!("Puzzle output: {}", output);
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
 }
 
-fn compute(input: &[&str]) -> Result<i64> {
+pub fn parse(input: &str) -> Result<Vec<RebootStep>> {
     let mut steps = Vec::new();
+    for (line_number, line) in numbered_lines(input) {
+        if line.is_empty() {
+            continue;
+        }
+        steps.push(RebootStep::from_str(line).map_err(|err| err.at_line(line_number))?);
+    }
+    Ok(steps)
+}
+
+pub fn part1(reboot_steps: &[RebootStep]) -> i64 {
+    // clamp steps outside of -50, 50
+    let clamped_steps = reboot_steps
+        .iter()
+        .filter_map(|step| {
+            step.clamp(
+                &INITIALIZATION_RANGE,
+                &INITIALIZATION_RANGE,
+                &INITIALIZATION_RANGE,
+            )
+        })
+        .collect::<Vec<RebootStep>>();
+
+    count_lit_cubes(&clamped_steps)
+}
 
-    for line in input {
-        let step = RebootStep::from_str(line)?;
+pub fn part2(reboot_steps: &[RebootStep]) -> i64 {
+    count_lit_cubes(reboot_steps)
+}
+
+fn count_lit_cubes(reboot_steps: &[RebootStep]) -> i64 {
+    let mut steps = Vec::new();
 
+    for step in reboot_steps.iter().cloned() {
         let mut intersections = Vec::new();
 
         for previous_step in &steps {
@@ -42,11 +81,11 @@ fn compute(input: &[&str]) -> Result<i64> {
         total_volume += step.volume();
     }
 
-    Ok(total_volume)
+    total_volume
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
-struct RebootStep {
+pub struct RebootStep {
     x_range: CoordRange,
     y_range: CoordRange,
     z_range: CoordRange,
@@ -55,7 +94,7 @@ struct RebootStep {
 }
 
 impl RebootStep {
-    fn new(x_range: CoordRange, y_range: CoordRange, z_range: CoordRange, lit: bool) -> Self {
+    pub fn new(x_range: CoordRange, y_range: CoordRange, z_range: CoordRange, lit: bool) -> Self {
         Self {
             x_range,
             y_range,
@@ -65,6 +104,28 @@ impl RebootStep {
         }
     }
 
+    // the part of this step that falls inside the given ranges, if any of it does
+    fn clamp(
+        &self,
+        x_range: &CoordRange,
+        y_range: &CoordRange,
+        z_range: &CoordRange,
+    ) -> Option<Self> {
+        if !self.x_range.overlaps(x_range)
+            || !self.y_range.overlaps(y_range)
+            || !self.z_range.overlaps(z_range)
+        {
+            return None;
+        }
+
+        Some(Self::new(
+            self.x_range.intersecting_range(x_range),
+            self.y_range.intersecting_range(y_range),
+            self.z_range.intersecting_range(z_range),
+            self.lit,
+        ))
+    }
+
     fn get_intersection(&self, previous_step: &Self) -> Option<Self> {
         if !self.x_range.overlaps(&previous_step.x_range)
             || !self.y_range.overlaps(&previous_step.y_range)
@@ -90,7 +151,7 @@ impl RebootStep {
         Some(new)
     }
 
-    fn volume(&self) -> i64 {
+    pub fn volume(&self) -> i64 {
         self.abs_volume
             * match self.lit {
                 true => 1,
@@ -100,25 +161,44 @@ impl RebootStep {
 }
 
 impl FromStr for RebootStep {
-    type Err = String;
+    type Err = AocError;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        let mut iter = s.split_whitespace();
-
-        let lit = iter.next().unwrap() == "on";
-
-        let mut ranges = iter.next().unwrap().split(',');
+        // on x=10..12,y=10..12,z=10..12
+        let (state, ranges) = s
+            .split_once(' ')
+            .ok_or_else(|| AocError::parse("a step like on x=10..12,y=10..12,z=10..12", s))?;
+
+        let lit = match state {
+            "on" => true,
+            "off" => false,
+            _ => return Err(AocError::parse("on or off", state).at_column(1)),
+        };
 
-        let x_range = CoordRange::from_str(ranges.next().unwrap())?;
-        let y_range = CoordRange::from_str(ranges.next().unwrap())?;
-        let z_range = CoordRange::from_str(ranges.next().unwrap())?;
+        let mut column = state.len() + 2;
+        let mut coord_ranges = Vec::with_capacity(3);
+        for (axis, range) in ['x', 'y', 'z'].into_iter().zip(ranges.split(',')) {
+            let coord_range = range
+                .strip_prefix(axis)
+                .and_then(|range| range.strip_prefix('='))
+                .ok_or_else(|| AocError::parse(format!("a range like {}=10..12", axis), range))
+                .and_then(|range| CoordRange::from_str(range).map_err(|err| err.at_column(3)))
+                .map_err(|err| err.at_column(column))?;
+            coord_ranges.push(coord_range);
+            column += range.len() + 1;
+        }
 
-        Ok(Self::new(x_range, y_range, z_range, lit))
+        match coord_ranges[..] {
+            [x_range, y_range, z_range] if ranges.split(',').count() == 3 => {
+                Ok(Self::new(x_range, y_range, z_range, lit))
+            }
+            _ => Err(AocError::parse("an x, y and z range", ranges).at_column(state.len() + 2)),
+        }
     }
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
-struct CoordRange(i32, i32);
+pub struct CoordRange(pub i32, pub i32);
 
 impl CoordRange {
     fn overlaps(&self, other: &Self) -> bool {
@@ -144,28 +224,23 @@ impl CoordRange {
 }
 
 impl FromStr for CoordRange {
-    type Err = String;
+    type Err = AocError;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        // split at =
-        // use part[1]
         // split at '..',
         // use parts 0,1 for start,end
-        let mut iter = s.split('=');
-        iter.next(); // drop the axis specifier
-
-        let mut num_iter = iter.next().unwrap().split("..");
-
-        let start = num_iter
-            .next()
-            .unwrap()
-            .parse::<i32>()
-            .map_err(|err| err.to_string())?;
-        let end = num_iter
-            .next()
-            .unwrap()
-            .parse::<i32>()
-            .map_err(|err| err.to_string())?;
+        let (start, end) = s
+            .split_once("..")
+            .ok_or_else(|| AocError::parse("a range like 10..12", s))?;
+
+        let start = parse_number::<i32>(start, "the start of a range")?;
+        let end = parse_number::<i32>(end, "the end of a range")
+            .map_err(|err| err.at_column(s.len() - end.len() + 1))?;
+
+        // the volume of a step is worked out assuming its ranges go upwards
+        if start > end {
+            return Err(AocError::parse("a range that starts before it ends", s));
+        }
 
         Ok(Self(start, end))
     }
@@ -176,7 +251,51 @@ mod tests {
     use super::*;
 
     #[test]
-    fn example1() -> Result<()> {
+    fn part1_example1() -> Result<()> {
+        let test_data = [
+            "on x=10..12,y=10..12,z=10..12",
+            "on x=11..13,y=11..13,z=11..13",
+            "off x=9..11,y=9..11,z=9..11",
+            "on x=10..10,y=10..10,z=10..10",
+        ];
+
+        assert_eq!(part1(&parse(&test_data.join("\n"))?), 39);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_example2() -> Result<()> {
+        let test_data = vec![
+            "on x=-20..26,y=-36..17,z=-47..7",
+            "on x=-20..33,y=-21..23,z=-26..28",
+            "on x=-22..28,y=-29..23,z=-38..16",
+            "on x=-46..7,y=-6..46,z=-50..-1",
+            "on x=-49..1,y=-3..46,z=-24..28",
+            "on x=2..47,y=-22..22,z=-23..27",
+            "on x=-27..23,y=-28..26,z=-21..29",
+            "on x=-39..5,y=-6..47,z=-3..44",
+            "on x=-30..21,y=-8..43,z=-13..34",
+            "on x=-22..26,y=-27..20,z=-29..19",
+            "off x=-48..-32,y=26..41,z=-47..-37",
+            "on x=-12..35,y=6..50,z=-50..-2",
+            "off x=-48..-32,y=-32..-16,z=-15..-5",
+            "on x=-18..26,y=-33..15,z=-7..46",
+            "off x=-40..-22,y=-38..-28,z=23..41",
+            "on x=-16..35,y=-41..10,z=-47..6",
+            "off x=-32..-23,y=11..30,z=-14..3",
+            "on x=-49..-5,y=-3..45,z=-29..18",
+            "off x=18..30,y=-20..-8,z=-3..13",
+            "on x=-41..9,y=-7..43,z=-33..15",
+            "on x=-54112..-39298,y=-85059..-49293,z=-27449..7877",
+            "on x=967..23432,y=45373..81175,z=27513..53682",
+        ];
+
+        assert_eq!(part1(&parse(&test_data.join("\n"))?), 590784);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example1() -> Result<()> {
         let test_data = vec![
             "on x=-5..47,y=-31..22,z=-19..33",
             "on x=-44..5,y=-27..21,z=-14..35",
@@ -240,20 +359,46 @@ mod tests {
             "off x=-93533..-4276,y=-16170..68771,z=-104985..-24507",
         ];
 
-        assert_eq!(compute(&test_data)?, 2758514936282235);
+        assert_eq!(part2(&parse(&test_data.join("\n"))?), 2758514936282235);
         Ok(())
     }
 
     #[test]
-    fn example2() -> Result<()> {
-        let test_data = vec![
+    fn part2_example2() -> Result<()> {
+        let test_data = [
             "on x=10..12,y=10..12,z=10..12",
             "on x=11..13,y=11..13,z=11..13",
             "off x=9..11,y=9..11,z=9..11",
             "on x=10..10,y=10..10,z=10..10",
         ];
 
-        assert_eq!(compute(&test_data)?, 39);
+        assert_eq!(part2(&parse(&test_data.join("\n"))?), 39);
         Ok(())
     }
+
+    #[test]
+    fn bad_steps() {
+        assert_eq!(
+            parse("on x=10..12,y=10..12,z=10..12\nof x=9..11,y=9..11,z=9..11").err(),
+            Some(AocError::parse("on or off", "of").at_line(2).at_column(1)),
+        );
+        assert_eq!(
+            parse("on x=10..12,y=10..1x,z=10..12").err(),
+            Some(
+                AocError::parse("the end of a range", "1x")
+                    .at_line(1)
+                    .at_column(19)
+            ),
+        );
+        assert_eq!(
+            parse("on x=10..12,z=10..12,y=10..12").err(),
+            Some(
+                AocError::parse("a range like y=10..12", "z=10..12")
+                    .at_line(1)
+                    .at_column(13)
+            ),
+        );
+        assert!(parse("on x=12..10,y=10..12,z=10..12").is_err());
+        assert!(parse("on x=10..12,y=10..12").is_err());
+    }
 }
@@ -0,0 +1,250 @@
+use std::cmp::Ordering;
+
+use crate::error::{numbered_lines, AocError};
+use crate::solution::{Answer, Result, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<String>> {
+    let mut report: Vec<String> = Vec::new();
+
+    for (line_number, line) in numbered_lines(input).filter(|(_, line)| !line.is_empty()) {
+        if let Some((column, bit)) = line
+            .chars()
+            .enumerate()
+            .find(|(_, c)| *c != '0' && *c != '1')
+        {
+            return Err(AocError::parse("a 0 or 1 bit", bit.to_string())
+                .at_line(line_number)
+                .at_column(column + 1));
+        }
+
+        if let Some(first) = report.first() {
+            if first.len() != line.len() {
+                return Err(
+                    AocError::parse(format!("a {} bit number", first.len()), line)
+                        .at_line(line_number),
+                );
+            }
+        }
+
+        report.push(line.to_string());
+    }
+
+    if report.is_empty() {
+        return Err(AocError::malformed("the diagnostic report is empty"));
+    }
+    Ok(report)
+}
+
+pub fn part1(report: &[String]) -> Result<i32> {
+    // iterate each line and add to a count for each bit, either 0 or 1
+    // so a a pair of vecs, index is bit position, value is count of either 0 or 1s
+    let report_number_length = report_number_length(report)?;
+
+    let lines: Vec<&str> = report.iter().map(String::as_str).collect();
+    let (zeros, ones) = find_zeros_and_ones(&lines, report_number_length);
+
+    // now we have our bits so we can make up a binary value for gamma and epsilon
+    let mut gamma = 0;
+    let mut epsilon = 0;
+    for (i, zero_count) in zeros.iter().enumerate() {
+        let ones_count = ones[i];
+
+        let exponent = report_number_length as u32 - 1 - i as u32;
+
+        if ones_count > *zero_count {
+            gamma += 2_i32.pow(exponent);
+        } else {
+            epsilon += 2_i32.pow(exponent);
+        }
+    }
+    Ok(gamma * epsilon)
+}
+
+pub fn part2(report: &[String]) -> Result<i32> {
+    let report_number_length = report_number_length(report)?;
+
+    // pseudo code
+    // get ones, zeros for all numbers
+    // find most common bit in idx 0
+    // filter numbers by that bit
+    // repeat until length of numbers = 0
+    let oxygen_number = find_and_filter(
+        report.iter().map(String::as_str).collect(),
+        report_number_length,
+        0,
+        oxygen_comparison,
+    )?;
+    let co2_number = find_and_filter(
+        report.iter().map(String::as_str).collect(),
+        report_number_length,
+        0,
+        co2_comparison,
+    )?;
+
+    // parse both numbers to decimal and multiply
+    let oxygen_number = i32::from_str_radix(oxygen_number, 2)
+        .map_err(|_| AocError::parse("a binary number", oxygen_number))?;
+    let co2_number = i32::from_str_radix(co2_number, 2)
+        .map_err(|_| AocError::parse("a binary number", co2_number))?;
+
+    Ok(oxygen_number * co2_number)
+}
+
+fn report_number_length(report: &[String]) -> Result<usize> {
+    if let Some(line) = report.first() {
+        Ok(line.len())
+    } else {
+        Err(AocError::malformed("the diagnostic report is empty"))
+    }
+}
+
+fn oxygen_comparison(z: i32, o: i32) -> char {
+    match z.cmp(&o) {
+        Ordering::Greater => '0',
+        Ordering::Equal => '1',
+        Ordering::Less => '1',
+    }
+}
+
+fn co2_comparison(z: i32, o: i32) -> char {
+    match z.cmp(&o) {
+        Ordering::Greater => '1',
+        Ordering::Equal => '0',
+        Ordering::Less => '0',
+    }
+}
+
+fn find_and_filter<F>(
+    lines: Vec<&str>,
+    report_number_length: usize,
+    depth: usize,
+    bit_comparison: F,
+) -> Result<&str>
+where
+    F: Fn(i32, i32) -> char,
+{
+    // identical numbers can never be filtered down to one
+    if depth >= report_number_length {
+        return Err(AocError::unsolvable(
+            "the report numbers can't be filtered down to a single rating",
+        ));
+    }
+
+    let (zeros, ones) = find_zeros_and_ones(&lines, report_number_length);
+
+    let most_common_bit = bit_comparison(zeros[depth], ones[depth]);
+
+    let mut new_lines: Vec<&str> = Vec::new();
+    // filter lines
+    for line in lines {
+        if line.chars().nth(depth) == Some(most_common_bit) {
+            new_lines.push(line);
+        }
+    }
+
+    if new_lines.len() == 1 {
+        Ok(new_lines[0])
+    } else {
+        find_and_filter(new_lines, report_number_length, depth + 1, bit_comparison)
+    }
+}
+
+fn find_zeros_and_ones(lines: &[&str], report_number_length: usize) -> (Vec<i32>, Vec<i32>) {
+    let mut zeros: Vec<i32> = vec![0; report_number_length];
+    let mut ones: Vec<i32> = vec![0; report_number_length];
+
+    for line in lines.iter() {
+        for (i, bit) in line.trim().chars().enumerate() {
+            match bit {
+                '0' => zeros[i] += 1,
+                '1' => ones[i] += 1,
+                _ => (),
+            }
+        }
+    }
+
+    (zeros, ones)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "00100
+            11110
+            10110
+            10111
+            10101
+            01111
+            00111
+            11100
+            10000
+            11001
+            00010
+            01010
+            ";
+
+    #[test]
+    fn part1_example1() -> Result<()> {
+        assert_eq!(part1(&parse(EXAMPLE)?)?, 198);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example1() -> Result<()> {
+        assert_eq!(part2(&parse(EXAMPLE)?)?, 230);
+        Ok(())
+    }
+
+    #[test]
+    fn empty_input() {
+        assert!(parse("").is_err());
+    }
+
+    #[test]
+    fn bad_bits() {
+        assert_eq!(
+            parse("00100\n11120").err(),
+            Some(AocError::parse("a 0 or 1 bit", "2").at_line(2).at_column(4))
+        );
+        assert_eq!(
+            parse("00100\n1111").err(),
+            Some(AocError::parse("a 5 bit number", "1111").at_line(2))
+        );
+    }
+
+    #[test]
+    fn duplicate_ratings() -> Result<()> {
+        assert!(matches!(
+            part2(&parse("101\n101")?).err(),
+            Some(AocError::Unsolvable(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn basic_find_zeros_and_ones() {
+        assert_eq!(
+            find_zeros_and_ones(&["000", "010", "101"], 3),
+            (vec![2, 2, 2], vec![1, 1, 1])
+        )
+    }
+}
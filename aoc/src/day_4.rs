@@ -1,21 +1,35 @@
-use std::error::Error;
-use std::fs;
 use std::str::FromStr;
 
-const INPUT_PATH: &str = "input.txt";
+use crate::error::{numbered_lines, parse_number, AocError};
+use crate::log::debug;
+use crate::solution::{Answer, Result, Solution};
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let raw_input = fs::read_to_string(INPUT_PATH)?;
-    let input: Vec<&str> = raw_input.lines().map(|line| line.trim()).collect();
+pub struct Day4;
 
-    let output = compute(&input)?;
+impl Solution for Day4 {
+    type Input = Bingo;
 
-    println!("Puzzle output: {}", output);
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
 }
 
-#[derive(Debug, PartialEq)]
-struct BingoNumber {
+#[derive(Debug, Clone)]
+pub struct Bingo {
+    pub drawn_numbers: Vec<i32>,
+    pub boards: Vec<BingoBoard>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct BingoNumber {
     number: i32,
     drawn: bool,
 }
@@ -30,43 +44,53 @@ impl BingoNumber {
 }
 
 impl FromStr for BingoNumber {
-    type Err = String;
+    type Err = AocError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            number: s.parse::<i32>().map_err(|err| err.to_string())?,
-            drawn: false,
-        })
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::new(parse_number(s, "a bingo number")?))
     }
 }
 
-#[derive(Debug)]
-struct BingoBoard {
+#[derive(Debug, Clone)]
+pub struct BingoBoard {
     numbers: Vec<BingoNumber>,
     rows: usize,
     columns: usize,
 }
 
 impl BingoBoard {
-    fn from_vec(lines: &[&str]) -> Result<Self, Box<dyn Error>> {
-        let numbers = lines
-            .iter()
-            .flat_map(|line| {
-                line.split_whitespace()
-                    .map(|number| BingoNumber::from_str(number))
-            })
-            .collect::<Result<Vec<BingoNumber>, String>>()?;
-        Ok(Self {
+    pub fn from_vec(lines: &[&str]) -> Result<Self> {
+        let mut numbers = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            numbers.extend(Self::parse_row(line).map_err(|err| err.at_line(i + 1))?);
+        }
+        Ok(Self::new(numbers))
+    }
+
+    fn new(numbers: Vec<BingoNumber>) -> Self {
+        Self {
             numbers,
             rows: 5,
             columns: 5,
-        })
+        }
+    }
+
+    fn parse_row(line: &str) -> Result<Vec<BingoNumber>> {
+        let row = line
+            .split_whitespace()
+            .map(BingoNumber::from_str)
+            .collect::<Result<Vec<BingoNumber>>>()?;
+
+        if row.len() != 5 {
+            return Err(AocError::parse("a row of 5 bingo numbers", line));
+        }
+        Ok(row)
     }
 
     fn apply_drawn_number(&mut self, drawn_number: i32) {
         for bingo_number in self.numbers.iter_mut() {
             if bingo_number.number == drawn_number {
-                (*bingo_number).drawn = true;
+                bingo_number.drawn = true;
             }
         }
     }
@@ -113,45 +137,88 @@ impl BingoBoard {
     }
 }
 
-fn build_boards(
-    input_iter: &mut std::slice::Iter<&str>,
-) -> Result<Vec<BingoBoard>, Box<dyn Error>> {
+fn build_boards<'a>(lines: impl Iterator<Item = (usize, &'a str)>) -> Result<Vec<BingoBoard>> {
     // need to iterate the lines, grouping into fives and then converting those 5 to a board
     let mut boards = Vec::new();
-    let mut board_lines: Vec<&str> = Vec::with_capacity(5);
-    for line in input_iter {
-        if !line.is_empty() {
-            board_lines.push(line);
+    let mut board_numbers: Vec<BingoNumber> = Vec::with_capacity(25);
+    let mut rows = 0;
+    for (line_number, line) in lines {
+        if line.is_empty() {
+            continue;
         }
 
-        if board_lines.len() == 5 {
-            boards.push(BingoBoard::from_vec(&board_lines)?);
-            board_lines.clear();
+        let row = BingoBoard::parse_row(line).map_err(|err| err.at_line(line_number))?;
+        board_numbers.extend(row);
+        rows += 1;
+
+        if rows == 5 {
+            boards.push(BingoBoard::new(board_numbers));
+            board_numbers = Vec::with_capacity(25);
+            rows = 0;
         }
     }
+
+    if rows > 0 {
+        return Err(AocError::malformed(format!(
+            "the last bingo board only has {} rows",
+            rows
+        )));
+    }
     Ok(boards)
 }
 
-fn compute(input: &[&str]) -> Result<i32, Box<dyn Error>> {
-    let mut input_iter = input.iter();
+pub fn parse(input: &str) -> Result<Bingo> {
+    let mut lines = numbered_lines(input);
 
-    let drawn_numbers = input_iter
+    let (line_number, drawn_numbers) = lines
         .next()
-        .ok_or_else(|| String::from("Invalid input for drawn_numbers"))?;
+        .ok_or_else(|| AocError::parse("the drawn numbers", ""))?;
 
     let drawn_numbers = drawn_numbers
         .split(',')
-        .map(|val| val.parse::<i32>())
-        .collect::<Result<Vec<i32>, _>>()?;
+        .map(|val| parse_number::<i32>(val, "a drawn number"))
+        .collect::<Result<Vec<i32>>>()
+        .map_err(|err| err.at_line(line_number))?;
 
     // build the bingo boards
-    let mut boards = build_boards(&mut input_iter)?;
+    let boards = build_boards(lines)?;
+
+    Ok(Bingo {
+        drawn_numbers,
+        boards,
+    })
+}
+
+pub fn part1(bingo: &Bingo) -> Result<i32> {
+    let mut boards = bingo.boards.clone();
+
+    // start applying numbers
+    for drawn_number in bingo.drawn_numbers.iter().cloned() {
+        for board in boards.iter_mut() {
+            board.apply_drawn_number(drawn_number);
+        }
+        // stop when a winner is found
+        for board in &boards {
+            if board.is_complete() {
+                return Ok(board.sum_unmarked() * drawn_number);
+            }
+        }
+    }
+
+    Err(AocError::unsolvable("no board wins with the drawn numbers"))
+}
+
+pub fn part2(bingo: &Bingo) -> Result<i32> {
+    let mut boards = bingo.boards.clone();
 
     // start applying numbers
     let mut num_of_incomplete_boards = boards.len();
 
-    for drawn_number in drawn_numbers {
-        println!("{}, {}", drawn_number, num_of_incomplete_boards);
+    for drawn_number in bingo.drawn_numbers.iter().cloned() {
+        debug!(
+            "Drew {}, {} boards left",
+            drawn_number, num_of_incomplete_boards
+        );
         for board in boards.iter_mut() {
             board.apply_drawn_number(drawn_number);
         }
@@ -163,10 +230,7 @@ fn compute(input: &[&str]) -> Result<i32, Box<dyn Error>> {
                     boards.remove(i);
                     num_of_incomplete_boards -= 1;
                 } else {
-                    println!(
-                        "{} {} {:?}",
-                        drawn_number, num_of_incomplete_boards, boards[i]
-                    );
+                    debug!("Last board complete on {}: {:?}", drawn_number, boards[i]);
                     return Ok(boards[i].sum_unmarked() * drawn_number);
                 }
             } else {
@@ -175,14 +239,44 @@ fn compute(input: &[&str]) -> Result<i32, Box<dyn Error>> {
         }
     }
 
-    Ok(0)
+    Err(AocError::unsolvable(
+        "the last board never wins with the drawn numbers",
+    ))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     #[test]
-    fn example1() {
+    fn part1_example1() -> Result<()> {
+        let test_data = vec![
+            "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1",
+            "",
+            "22 13 17 11  0",
+            " 8  2 23  4 24",
+            "21  9 14 16  7",
+            " 6 10  3 18  5",
+            " 1 12 20 15 19",
+            "",
+            " 3 15  0  2 22",
+            " 9 18 13 17  5",
+            "19  8  7 25 23",
+            "20 11 10 24  4",
+            "14 21 16 12  6",
+            "",
+            "14 21 17 24  4",
+            "10 16 15  9 19",
+            "18  8 23 26 20",
+            "22 11 13  6  5",
+            " 2  0 12  3  7",
+        ];
+
+        assert_eq!(part1(&parse(&test_data.join("\n"))?)?, 4512);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example1() -> Result<()> {
         let test_data = vec![
             "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1",
             "",
@@ -206,7 +300,8 @@ mod tests {
             "",
         ];
 
-        assert_eq!(compute(&test_data).unwrap(), 1924)
+        assert_eq!(part2(&parse(&test_data.join("\n"))?)?, 1924);
+        Ok(())
     }
 
     #[test]
@@ -304,7 +399,12 @@ mod tests {
             " 2  0 12  3  7",
         ];
 
-        assert_eq!(build_boards(&mut test_data.iter()).unwrap().len(), 3)
+        assert_eq!(
+            build_boards(numbered_lines(&test_data.join("\n")))
+                .unwrap()
+                .len(),
+            3
+        )
     }
 
     #[test]
@@ -389,4 +489,27 @@ mod tests {
         board.apply_drawn_number(2);
         assert_eq!(board.sum_unmarked(), 8)
     }
+
+    #[test]
+    fn bad_board_number() {
+        assert_eq!(
+            parse("7,4,9\n\n22 13 17 11  0\n 8  2 2x  4 24").err(),
+            Some(AocError::parse("a bingo number", "2x").at_line(4))
+        );
+    }
+
+    #[test]
+    fn short_board_row() {
+        assert_eq!(
+            parse("7,4,9\n\n22 13 17 11").err(),
+            Some(AocError::parse("a row of 5 bingo numbers", "22 13 17 11").at_line(3))
+        );
+    }
+
+    #[test]
+    fn no_winner() -> Result<()> {
+        let bingo = parse("7\n\n1 2 3 4 5\n1 2 3 4 5\n1 2 3 4 5\n1 2 3 4 5\n1 2 3 4 5")?;
+        assert!(matches!(part1(&bingo), Err(AocError::Unsolvable(_))));
+        Ok(())
+    }
 }
@@ -1,51 +1,60 @@
 use std::collections::HashMap;
-use std::error::Error;
-use std::fs;
 use std::str::FromStr;
 
-const INPUT_PATH: &str = "input.txt";
+use crate::error::{numbered_lines, parse_number, AocError};
+use crate::solution::{Answer, Result, Solution};
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let raw_input = fs::read_to_string(INPUT_PATH)?;
-    let input: Vec<&str> = raw_input.lines().map(|line| line.trim()).collect();
+pub struct Day5;
 
-    let output = compute(&input)?;
+impl Solution for Day5 {
+    type Input = Vec<Line>;
 
-    println!("Puzzle output: {}", output);
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
 }
 
 #[derive(PartialEq, Eq, Hash, Debug)]
-struct Point(i32, i32);
+pub struct Point(pub i32, pub i32);
 
 impl FromStr for Point {
-    type Err = Box<dyn Error>;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let data = s
-            .split(',')
-            .map(|val| val.parse::<i32>())
-            .collect::<Result<Vec<i32>, _>>()?;
-        Ok(Self {
-            0: data[0],
-            1: data[1],
-        })
+    type Err = AocError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| AocError::parse("a point like 0,9", s))?;
+
+        Ok(Self(
+            parse_number(x, "an x coordinate")?,
+            parse_number(y, "a y coordinate")?,
+        ))
     }
 }
 
 #[derive(Debug)]
-struct Line {
-    points: Vec<Point>,
+pub struct Line {
+    pub points: Vec<Point>,
+    diagonal: bool,
 }
 
 impl Line {
-    fn new(p1: Point, p2: Point) -> Self {
+    pub fn new(p1: Point, p2: Point) -> Self {
         // need to sort points
         // iterate along them, filling in the details
         // points will be horizontal or vertical
 
         // find common value
         let mut points = Vec::new();
+        let diagonal = p1.0 != p2.0 && p1.1 != p2.1;
         if p1.0 == p2.0 {
             //x is common
             for y in std::cmp::min(p1.1, p2.1)..=std::cmp::max(p1.1, p2.1) {
@@ -82,55 +91,82 @@ impl Line {
             }
         }
 
-        Self { points }
+        Self { points, diagonal }
+    }
+
+    // part 1 only considers horizontal and vertical lines
+    pub fn is_diagonal(&self) -> bool {
+        self.diagonal
     }
 }
 
 impl FromStr for Line {
-    type Err = Box<dyn Error>;
+    type Err = AocError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        // 0,9 -> 5,9
+        let (start, end) = s
+            .split_once(" -> ")
+            .ok_or_else(|| AocError::parse("a line like 0,9 -> 5,9", s))?;
+        let start = Point::from_str(start.trim()).map_err(|err| err.at_column(1))?;
+        let end = Point::from_str(end.trim()).map_err(|err| err.at_column(start_len(s) + 1))?;
+
+        // diagonal lines are always at 45 degrees
+        if start.0 != end.0
+            && start.1 != end.1
+            && (start.0 - end.0).abs() != (start.1 - end.1).abs()
+        {
+            return Err(AocError::parse(
+                "a horizontal, vertical or 45 degree line",
+                s,
+            ));
+        }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut iter = s.split_whitespace();
-        let start = Point::from_str(iter.next().unwrap())?;
-        iter.next();
-        let end = Point::from_str(iter.next().unwrap())?;
         Ok(Self::new(start, end))
     }
 }
 
-fn compute(input: &[&str]) -> Result<i32, Box<dyn Error>> {
-    // parse data
-    let lines = input
-        .iter()
-        .map(|val| Line::from_str(val))
-        .collect::<Result<Vec<Line>, _>>()?;
+// where the end point starts within a line
+fn start_len(s: &str) -> usize {
+    s.find(" -> ").map_or(0, |idx| idx + 4)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Line>> {
+    numbered_lines(input)
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line_number, line)| Line::from_str(line).map_err(|err| err.at_line(line_number)))
+        .collect()
+}
+
+pub fn part1(lines: &[Line]) -> usize {
+    count_overlaps(lines.iter().filter(|line| !line.is_diagonal()))
+}
+
+pub fn part2(lines: &[Line]) -> usize {
+    count_overlaps(lines.iter())
+}
+
+fn count_overlaps<'a>(lines: impl Iterator<Item = &'a Line>) -> usize {
     // for line in lines
     // for point in line.points
     // build up hashmap of points
-    let mut map: HashMap<Point, usize> = HashMap::new();
+    let mut map: HashMap<&Point, usize> = HashMap::new();
     for line in lines {
-        for point in line.points {
+        for point in &line.points {
             let value = map.entry(point).or_insert(0);
             *value += 1;
         }
     }
 
     // count number of points with value >= 2
-    let mut count = 0;
-    for (_, val) in map.iter() {
-        if *val >= 2 {
-            count += 1;
-        }
-    }
-
-    Ok(count)
+    map.values().filter(|val| **val >= 2).count()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     #[test]
-    fn example1() {
+    fn part1_example1() -> Result<()> {
         let test_data = vec![
             "0,9 -> 5,9",
             "8,0 -> 0,8",
@@ -144,28 +180,51 @@ mod tests {
             "5,5 -> 8,2",
         ];
 
-        assert_eq!(compute(&test_data).unwrap(), 12)
+        assert_eq!(part1(&parse(&test_data.join("\n"))?), 5);
+        Ok(())
     }
 
     #[test]
-    fn example2() {
-        let test_data = vec!["0,0 -> 0,4", "0,0 -> 0,1"];
+    fn part2_example1() -> Result<()> {
+        let test_data = vec![
+            "0,9 -> 5,9",
+            "8,0 -> 0,8",
+            "9,4 -> 3,4",
+            "2,2 -> 2,1",
+            "7,0 -> 7,4",
+            "6,4 -> 2,0",
+            "0,9 -> 2,9",
+            "3,4 -> 1,4",
+            "0,0 -> 8,8",
+            "5,5 -> 8,2",
+        ];
+
+        assert_eq!(part2(&parse(&test_data.join("\n"))?), 12);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_example2() -> Result<()> {
+        let test_data = ["0,0 -> 0,4", "0,0 -> 0,1"];
 
-        assert_eq!(compute(&test_data).unwrap(), 2)
+        assert_eq!(part1(&parse(&test_data.join("\n"))?), 2);
+        Ok(())
     }
 
     #[test]
-    fn example3() {
-        let test_data = vec!["0,0 -> 0,4", "1,0 -> 1,1"];
+    fn part1_example3() -> Result<()> {
+        let test_data = ["0,0 -> 0,4", "1,0 -> 1,1"];
 
-        assert_eq!(compute(&test_data).unwrap(), 0)
+        assert_eq!(part1(&parse(&test_data.join("\n"))?), 0);
+        Ok(())
     }
 
     #[test]
-    fn example4() {
-        let test_data = vec!["0,0 -> 0,4", "0,0 -> 0,1", "0,4 -> 2,4"];
+    fn part1_example4() -> Result<()> {
+        let test_data = ["0,0 -> 0,4", "0,0 -> 0,1", "0,4 -> 2,4"];
 
-        assert_eq!(compute(&test_data).unwrap(), 3)
+        assert_eq!(part1(&parse(&test_data.join("\n"))?), 3);
+        Ok(())
     }
 
     #[test]
@@ -266,4 +325,21 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn bad_lines() {
+        assert_eq!(
+            parse("0,9 -> 5,9\n8,0 -> 0,x").err(),
+            Some(
+                AocError::parse("a y coordinate", "x")
+                    .at_line(2)
+                    .at_column(8)
+            )
+        );
+        assert_eq!(
+            parse("0,9 => 5,9").err(),
+            Some(AocError::parse("a line like 0,9 -> 5,9", "0,9 => 5,9").at_line(1))
+        );
+        assert!(parse("0,0 -> 2,1").is_err());
+    }
 }
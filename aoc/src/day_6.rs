@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use crate::error::{parse_number, AocError};
+use crate::solution::{Answer, Result, Solution};
+
+pub const PART1_DAYS: i32 = 80;
+pub const PART2_DAYS: i32 = 256;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(count_fish(input, PART1_DAYS).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(count_fish(input, PART2_DAYS).into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<i32>> {
+    let line = input.trim();
+    let mut column = 1;
+    let mut fish_school = Vec::new();
+    for val in line.split(',') {
+        // a fish's timer never goes above 8, anything larger isn't in the reproduction map
+        let fish = parse_number::<i32>(val, "a timer from 0 to 8")
+            .and_then(|fish| match fish {
+                0..=8 => Ok(fish),
+                _ => Err(AocError::parse("a timer from 0 to 8", val.trim())),
+            })
+            .map_err(|err| err.at_line(1).at_column(column))?;
+        fish_school.push(fish);
+        column += val.len() + 1;
+    }
+    Ok(fish_school)
+}
+
+pub fn part1(fish_school: &[i32]) -> i64 {
+    count_fish(fish_school, PART1_DAYS)
+}
+
+pub fn part2(fish_school: &[i32]) -> i64 {
+    count_fish(fish_school, PART2_DAYS)
+}
+
+pub fn count_fish(fish_school: &[i32], simulation_length_in_days: i32) -> i64 {
+    let days_from_end_born_at = fish_school
+        .iter()
+        .map(|fish| 8 - fish + simulation_length_in_days) // 8 is the maximum days ago that a fish could have been born
+        .collect::<Vec<i32>>();
+
+    let fish_reproduction_map = compute_reproduction_map(simulation_length_in_days);
+
+    let mut count = 0;
+    for fish in days_from_end_born_at {
+        count += 1;
+        count += fish_reproduction_map
+            .get(&fish)
+            .expect("parse only allows timers that are in the reproduction map");
+    }
+
+    count
+}
+
+fn compute_reproduction_map(simulation_length_in_days: i32) -> HashMap<i32, i64> {
+    let mut map = HashMap::new();
+
+    let mut born_at_days_from_end = 0;
+    while born_at_days_from_end <= simulation_length_in_days + 9 {
+        map.insert(
+            born_at_days_from_end,
+            compute_number_of_offspring(born_at_days_from_end, &map),
+        );
+        born_at_days_from_end += 1;
+    }
+
+    map
+}
+
+fn compute_number_of_offspring(born_at_days_from_end: i32, map: &HashMap<i32, i64>) -> i64 {
+    // if a fish is born less than 9 days from the end than it can't reproduce
+    if born_at_days_from_end < 9 {
+        return 0;
+    }
+
+    // count of how many fish this fish directly produced
+    let mut offspring_count: i64 = ((i64::from(born_at_days_from_end) - 9) / 7) + 1;
+
+    // fish we indirectly reproduced = count from map at each birth date
+    let mut child_birth_date = born_at_days_from_end - 9;
+    while child_birth_date >= 0 {
+        offspring_count += map.get(&child_birth_date).unwrap_or_else(|| {
+            panic!(
+                "Map doesn't contain key for birth date: {}",
+                child_birth_date
+            )
+        });
+        child_birth_date -= 7;
+    }
+
+    offspring_count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn part1_example1() -> Result<()> {
+        assert_eq!(part1(&parse("3,4,3,1,2")?), 5934);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example1() -> Result<()> {
+        assert_eq!(part2(&parse("3,4,3,1,2")?), 26984457539);
+        Ok(())
+    }
+
+    #[test]
+    fn example_18_days() -> Result<()> {
+        assert_eq!(count_fish(&parse("3,4,3,1,2")?, 18), 26);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_timers() {
+        assert_eq!(
+            parse("3,4,x,1").err(),
+            Some(
+                AocError::parse("a timer from 0 to 8", "x")
+                    .at_line(1)
+                    .at_column(5)
+            )
+        );
+        assert_eq!(
+            parse("3,9").err(),
+            Some(
+                AocError::parse("a timer from 0 to 8", "9")
+                    .at_line(1)
+                    .at_column(3)
+            )
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use crate::error::{parse_number, AocError};
+use crate::solution::{Answer, Result, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<i32>> {
+    let line = input.trim();
+    if line.is_empty() {
+        return Err(AocError::malformed("there are no crab positions"));
+    }
+
+    let mut column = 1;
+    let mut positions = Vec::new();
+    for val in line.split(',') {
+        let position = parse_number::<i32>(val, "a crab position")
+            .map_err(|err| err.at_line(1).at_column(column))?;
+        positions.push(position);
+        column += val.len() + 1;
+    }
+
+    positions.sort_unstable();
+    Ok(positions)
+}
+
+pub fn part1(positions: &[i32]) -> i32 {
+    lowest_fuel(positions, |distance| distance)
+}
+
+pub fn part2(positions: &[i32]) -> i32 {
+    lowest_fuel(positions, fuel_calculation)
+}
+
+// positions must be sorted
+fn lowest_fuel<F>(positions: &[i32], fuel_calculation: F) -> i32
+where
+    F: Fn(i32) -> i32,
+{
+    let min = positions[0];
+    let max = positions[positions.len() - 1];
+
+    // iterate from min to max, calculate fuel usage to get all crabs here
+    //
+    // alternatively, start at the middle of the sorted list, calculate for all, move to 1/4 then
+    // 3/4 and do the same?
+    //
+    // is this some form of binary search?
+    let mut current_lowest_fuel = i32::MAX;
+    for selected_position in min..=max {
+        let mut fuel_usage = 0;
+        for crab_pos in positions {
+            fuel_usage += fuel_calculation((crab_pos - selected_position).abs());
+        }
+
+        if fuel_usage < current_lowest_fuel {
+            current_lowest_fuel = fuel_usage;
+        }
+    }
+
+    current_lowest_fuel
+}
+
+fn fuel_calculation(distance: i32) -> i32 {
+    let mut fuel_total = 0;
+    for i in 0..=distance {
+        fuel_total += i;
+    }
+    fuel_total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn part1_example1() -> Result<()> {
+        assert_eq!(part1(&parse("16,1,2,0,4,2,7,1,2,14")?), 37);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example1() -> Result<()> {
+        assert_eq!(part2(&parse("16,1,2,0,4,2,7,1,2,14")?), 168);
+        Ok(())
+    }
+
+    #[test]
+    fn fuel_calc() {
+        assert_eq!(fuel_calculation(4), 10)
+    }
+
+    #[test]
+    fn bad_positions() {
+        assert_eq!(
+            parse("16,1,-").err(),
+            Some(
+                AocError::parse("a crab position", "-")
+                    .at_line(1)
+                    .at_column(6)
+            )
+        );
+        assert_eq!(
+            parse("\n").err(),
+            Some(AocError::malformed("there are no crab positions"))
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{numbered_lines, AocError};
+use crate::solution::{Answer, Result, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Entry>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
+
+// one line of notes: the ten unique signal patterns and the four digit output value
+#[derive(Debug)]
+pub struct Entry {
+    pub signal_patterns: Vec<HashSet<char>>,
+    pub output_values: Vec<HashSet<char>>,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Entry>> {
+    let mut entries = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        if line.is_empty() {
+            continue;
+        }
+
+        let (raw_patterns, raw_output) = line
+            .split_once('|')
+            .ok_or_else(|| AocError::parse("signal patterns | output values", line))?;
+
+        let signal_patterns = to_char_sets(raw_patterns, 10, "ten signal patterns")
+            .map_err(|err| err.at_line(line_number))?;
+        let output_values = to_char_sets(raw_output, 4, "four output values")
+            .map_err(|err| err.at_line(line_number).at_column(raw_patterns.len() + 2))?;
+
+        entries.push(Entry {
+            signal_patterns,
+            output_values,
+        });
+    }
+
+    Ok(entries)
+}
+
+fn to_char_sets(raw: &str, count: usize, expected: &str) -> Result<Vec<HashSet<char>>> {
+    let mut sets = Vec::new();
+    for val in raw.split_whitespace() {
+        if !val.chars().all(|c| ('a'..='g').contains(&c)) {
+            let offset = val.as_ptr() as usize - raw.as_ptr() as usize;
+            return Err(AocError::parse("segments a to g", val).at_column(offset + 1));
+        }
+        sets.push(val.chars().collect::<HashSet<char>>());
+    }
+
+    if sets.len() != count {
+        return Err(AocError::parse(expected, raw.trim()));
+    }
+    Ok(sets)
+}
+
+pub fn part1(entries: &[Entry]) -> usize {
+    // 1, 4, 7 and 8 are the only digits with a unique number of segments
+    entries
+        .iter()
+        .flat_map(|entry| entry.output_values.iter())
+        .filter(|val| matches!(val.len(), 2 | 4 | 3 | 7))
+        .count()
+}
+
+pub fn part2(entries: &[Entry]) -> Result<usize> {
+    let mut count: usize = 0;
+    for (entry_number, entry) in entries.iter().enumerate() {
+        let number_mappings =
+            build_char_set_number_mapping(&entry.signal_patterns).ok_or_else(|| {
+                AocError::unsolvable(format!(
+                    "the signal patterns of entry {} don't make ten digits",
+                    entry_number + 1
+                ))
+            })?;
+
+        // convert output sets to numbers, then build up the value a digit at a time
+        let mut value = 0;
+        for output_set in &entry.output_values {
+            let digit = number_mappings
+                .iter()
+                .find(|(_, number_mapping)| output_set == **number_mapping)
+                .map(|(i, _)| *i)
+                .ok_or_else(|| {
+                    AocError::unsolvable(format!(
+                        "an output value of entry {} isn't one of its digits",
+                        entry_number + 1
+                    ))
+                })?;
+            value = value * 10 + digit;
+        }
+        count += value;
+    }
+
+    Ok(count)
+}
+
+// this function takes a list of patterns and works out which segment lines up with which wire
+// returned is a mapping of numbers to sets of characters, or None if the patterns don't decode
+fn build_char_set_number_mapping(
+    signal_patterns: &[HashSet<char>],
+) -> Option<HashMap<usize, &HashSet<char>>> {
+    // TODO
+    // numbers to do 3
+    // segments to do: n/a
+    let mut numbers = HashMap::new();
+    // numbers done: 0, 1, 2, 4, 5, 6, 7, 8, 9
+    // segments done: 0, 1, 2, 3, 4, 6
+    // segments done: a, b, c, d, e, g
+
+    // a,b,c,d,e,f,g == indices 0,1,2,3,4,5,6
+    // so the character at index 0 is display segment 0
+    let mut segments = HashMap::new();
+
+    for pattern in signal_patterns {
+        match pattern.len() {
+            2 => numbers.insert(1, pattern),
+            3 => numbers.insert(7, pattern),
+            4 => numbers.insert(4, pattern),
+            7 => numbers.insert(8, pattern),
+            _ => None,
+        };
+    }
+
+    // can work out segment 0 from number 7 - 4
+    segments.insert(
+        0,
+        *digit(&numbers, 7)?.difference(digit(&numbers, 4)?).next()?,
+    );
+
+    // number 9 == 6 length and intersects with 4 completely
+    // number 0 == length 6, not 9, intersects with 1
+    // number 6 == 6 length and doesn't intersect with 4 completely
+    for pattern in signal_patterns {
+        if pattern.len() == 6 {
+            if digit(&numbers, 4)?.is_subset(pattern) {
+                numbers.insert(9, pattern);
+            } else if digit(&numbers, 1)?.is_subset(pattern) {
+                numbers.insert(0, pattern);
+            } else {
+                numbers.insert(6, pattern);
+            }
+        }
+    }
+
+    // segment 6 == 9 - 4 - 7
+    let segments_1_2_3_4_6 = digit(&numbers, 4)?
+        .union(digit(&numbers, 7)?)
+        .cloned()
+        .collect::<HashSet<char>>();
+    segments.insert(
+        6,
+        *digit(&numbers, 9)?.difference(&segments_1_2_3_4_6).next()?,
+    );
+
+    // segment 4 == 9 - 8
+    segments.insert(
+        4,
+        *digit(&numbers, 8)?.difference(digit(&numbers, 9)?).next()?,
+    );
+
+    // segment 3 == difference of 8 and 0
+    segments.insert(
+        3,
+        *digit(&numbers, 8)?.difference(digit(&numbers, 0)?).next()?,
+    );
+
+    // segment 1 == number 4 - number 1 and segment d/3
+    segments.insert(
+        1,
+        **digit(&numbers, 4)?
+            .difference(digit(&numbers, 1)?)
+            .collect::<HashSet<&char>>()
+            .difference(&[*segments.get(&3)?].iter().collect())
+            .next()?,
+    );
+
+    // number 2 == pattern with len == 5 and segments a,d,e,g
+    // number 5 == pattern with len == 5, and segment b
+    for pattern in signal_patterns {
+        if pattern.len() == 5 {
+            if pattern.is_superset(
+                &[
+                    *segments.get(&0)?,
+                    *segments.get(&3)?,
+                    *segments.get(&4)?,
+                    *segments.get(&6)?,
+                ]
+                .iter()
+                .cloned()
+                .collect(),
+            ) {
+                numbers.insert(2, pattern);
+            } else if pattern.is_superset(&[*segments.get(&1)?].iter().cloned().collect()) {
+                numbers.insert(5, pattern);
+            }
+        }
+    }
+
+    // segment 2 = 2 - adge
+    segments.insert(
+        2,
+        *digit(&numbers, 2)?
+            .difference(
+                &[
+                    *segments.get(&0)?,
+                    *segments.get(&3)?,
+                    *segments.get(&4)?,
+                    *segments.get(&6)?,
+                ]
+                .iter()
+                .cloned()
+                .collect(),
+            )
+            .next()?,
+    );
+
+    // segments 5 is 1 - segment 2
+    segments.insert(
+        5,
+        *digit(&numbers, 1)?
+            .difference(&[*segments.get(&2)?].iter().cloned().collect())
+            .next()?,
+    );
+
+    // number 3
+    for pattern in signal_patterns {
+        if pattern.len() == 5 && pattern.is_superset(digit(&numbers, 1)?) {
+            numbers.insert(3, pattern);
+        }
+    }
+
+    // every digit needs its own pattern for the output values to be read
+    (numbers.len() == 10).then_some(numbers)
+}
+
+fn digit<'a>(
+    numbers: &HashMap<usize, &'a HashSet<char>>,
+    number: usize,
+) -> Option<&'a HashSet<char>> {
+    numbers.get(&number).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> String {
+        let test_data: Vec<&str> = vec![
+"be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe",
+"edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc",
+"fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg",
+"fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb",
+"aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea",
+"fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb",
+"dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe",
+"bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef",
+"egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb",
+"gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce",
+        ];
+
+        test_data.join("\n")
+    }
+
+    #[test]
+    fn part1_example1() -> Result<()> {
+        assert_eq!(part1(&parse(&example())?), 26);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example1() -> Result<()> {
+        assert_eq!(part2(&parse(&example())?)?, 61229);
+        Ok(())
+    }
+
+    #[test]
+    fn missing_output() {
+        assert!(parse("be cfbegad cbdgef").is_err());
+    }
+
+    #[test]
+    fn bad_segments() {
+        assert_eq!(
+            parse("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gchx")
+                .err(),
+            Some(AocError::parse("segments a to g", "gchx").at_line(1).at_column(83)),
+        );
+    }
+
+    #[test]
+    fn undecodable_entry() -> Result<()> {
+        let entries = parse("ab ab ab ab ab ab ab ab ab ab | ab ab ab ab")?;
+        assert!(matches!(part2(&entries), Err(AocError::Unsolvable(_))));
+        Ok(())
+    }
+}
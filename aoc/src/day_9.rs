@@ -0,0 +1,343 @@
+use std::collections::HashSet;
+
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::render::{highlight, paint, palette, Image, RenderFormat, WHITE};
+use crate::solution::{Answer, Result, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = HeightMap;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
+
+// heights of this or more are walls between the basins
+pub const WALL_HEIGHT: u32 = 9;
+
+pub struct HeightMap {
+    heights: Grid<u32>,
+    wall_height: u32,
+}
+
+impl HeightMap {
+    pub fn with_wall_height(mut self, wall_height: u32) -> Self {
+        self.wall_height = wall_height;
+        self
+    }
+
+    pub fn item_at(&self, x: usize, y: usize) -> Option<u32> {
+        self.heights.get(x, y).copied()
+    }
+
+    // a low point is lower than everything above, below and beside it
+    fn is_low_point(&self, x: usize, y: usize) -> bool {
+        let height = self.heights[(x, y)];
+        self.heights
+            .neighbours4(x, y)
+            .all(|neighbour| self.heights[neighbour] > height)
+    }
+
+    fn is_wall(&self, position: (usize, usize)) -> bool {
+        self.heights[position] >= self.wall_height
+    }
+
+    // Every area closed in by walls or the edge of the map, numbered in the order their first
+    // cells come row by row
+    pub fn basins(&self) -> Vec<Basin> {
+        let mut labels = Grid::filled(self.heights.width(), self.heights.height(), None);
+        let mut basins = Vec::new();
+
+        for position in self.heights.positions() {
+            // if we've already been here in a basin, or it's a wall between basins, move along
+            if self.is_wall(position) || labels[position].is_some() {
+                continue;
+            }
+
+            let id = basins.len();
+            let mut basin = Basin {
+                id,
+                low_point: position,
+                cells: vec![position],
+            };
+            labels[position] = Some(id);
+
+            // flood out from here, spreading to neighbours that aren't walls and haven't been seen
+            let mut edge = vec![position];
+            while let Some((x, y)) = edge.pop() {
+                for neighbour in self.heights.neighbours4(x, y) {
+                    if !self.is_wall(neighbour) && labels[neighbour].is_none() {
+                        labels[neighbour] = Some(id);
+                        basin.cells.push(neighbour);
+                        edge.push(neighbour);
+                    }
+                }
+            }
+
+            basin.cells.sort_unstable_by_key(|&(x, y)| (y, x));
+            basin.low_point = basin
+                .cells
+                .iter()
+                .copied()
+                .min_by_key(|&position| self.heights[position])
+                .unwrap_or(position);
+            basins.push(basin);
+        }
+
+        basins
+    }
+
+    // the id of the basin each position is in, None for walls
+    pub fn basin_labels(&self) -> Grid<Option<usize>> {
+        self.label(&self.basins())
+    }
+
+    fn label(&self, basins: &[Basin]) -> Grid<Option<usize>> {
+        let mut labels = Grid::filled(self.heights.width(), self.heights.height(), None);
+        for basin in basins {
+            for &position in &basin.cells {
+                labels[position] = Some(basin.id);
+            }
+        }
+        labels
+    }
+
+    // Each basin in its own colour with its low point picked out, walls stay plain
+    pub fn render_ansi(&self) -> String {
+        let basins = self.basins();
+        let labels = self.label(&basins);
+        let low_points: HashSet<(usize, usize)> =
+            basins.iter().map(|basin| basin.low_point).collect();
+
+        let mut rendered = String::new();
+        for (position, height) in self.heights.iter() {
+            match labels[position] {
+                Some(_) if low_points.contains(&position) => rendered.push_str(&highlight(height)),
+                Some(id) => rendered.push_str(&paint(height, palette(id))),
+                None => rendered.push_str(&height.to_string()),
+            }
+            if position.0 == self.heights.width() - 1 {
+                rendered.push('\n');
+            }
+        }
+        rendered
+    }
+
+    // each basin in its own colour, walls in black and low points in white
+    pub fn render_image(&self) -> Image {
+        let mut image = Image::new(self.heights.width(), self.heights.height());
+        for basin in self.basins() {
+            for &(x, y) in &basin.cells {
+                image.set(x, y, palette(basin.id));
+            }
+            image.set(basin.low_point.0, basin.low_point.1, WHITE);
+        }
+        image
+    }
+}
+
+// an area of the map that drains to the same place
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Basin {
+    pub id: usize,
+    // the lowest cell, the first row by row if there's a tie
+    pub low_point: (usize, usize),
+    // row by row
+    pub cells: Vec<(usize, usize)>,
+}
+
+impl Basin {
+    pub fn size(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+pub fn parse(input: &str) -> Result<HeightMap> {
+    Ok(HeightMap {
+        heights: Grid::parse_digits(input, "heights", "a height")?,
+        wall_height: WALL_HEIGHT,
+    })
+}
+
+pub fn part1(map: &HeightMap) -> u32 {
+    map.heights
+        .iter()
+        .filter(|((x, y), _)| map.is_low_point(*x, *y))
+        .map(|(_, height)| 1 + height)
+        .sum()
+}
+
+// the sizes of the three biggest basins multiplied together
+pub fn part2(map: &HeightMap) -> Result<u32> {
+    let mut sizes: Vec<u32> = map
+        .basins()
+        .iter()
+        .map(|basin| basin.size() as u32)
+        .collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+    if sizes.len() < 3 {
+        return Err(AocError::unsolvable(format!(
+            "only {} basins in the height map, need 3",
+            sizes.len()
+        )));
+    }
+
+    Ok(sizes[..3].iter().product())
+}
+
+// draw the basins, which are the same for both parts
+pub fn render(map: &HeightMap, format: RenderFormat) -> Result<Vec<u8>> {
+    match format {
+        RenderFormat::Ansi => Ok(map.render_ansi().into_bytes()),
+        RenderFormat::Ppm => Ok(map.render_image().to_ppm()),
+        RenderFormat::Dot => Err(AocError::unsolvable("day 9 is a map, not a graph")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn part1_example1() -> Result<()> {
+        let test_data = [
+            "2199943210",
+            "3987894921",
+            "9856789892",
+            "8767896789",
+            "9899965678",
+        ];
+        assert_eq!(part1(&parse(&test_data.join("\n"))?), 15);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_example2() -> Result<()> {
+        let test_data = ["210", "921", "892"];
+        assert_eq!(part1(&parse(&test_data.join("\n"))?), 10);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_example3() -> Result<()> {
+        let test_data = [
+            "1210", // 1, 0
+            "6921", "2892", // 2
+            "4321", "1881", // 1
+        ];
+        assert_eq!(part1(&parse(&test_data.join("\n"))?), 8);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example1() -> Result<()> {
+        let test_data = [
+            "2199943210",
+            "3987894921",
+            "9856789892",
+            "8767896789",
+            "9899965678",
+        ];
+        assert_eq!(part2(&parse(&test_data.join("\n"))?)?, 1134);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_height() {
+        assert!(parse("21a").is_err());
+        assert_eq!(
+            parse("219\n3a8").err(),
+            Some(AocError::parse("a height", "a").at_line(2).at_column(2)),
+        );
+    }
+
+    #[test]
+    fn ragged_rows() {
+        assert_eq!(
+            parse("219\n39").err(),
+            Some(AocError::parse("a row of 3 heights", "39").at_line(2)),
+        );
+    }
+
+    #[test]
+    fn label_basins() -> Result<()> {
+        let map = parse("2199943210\n3987894921\n9856789892\n8767896789\n9899965678")?;
+        let basins = map.basins();
+
+        assert_eq!(
+            basins.iter().map(Basin::size).collect::<Vec<_>>(),
+            vec![3, 9, 14, 9]
+        );
+        assert_eq!(
+            basins[0],
+            Basin {
+                id: 0,
+                low_point: (1, 0),
+                cells: vec![(0, 0), (1, 0), (0, 1)],
+            }
+        );
+        assert_eq!(
+            basins
+                .iter()
+                .map(|basin| basin.low_point)
+                .collect::<Vec<_>>(),
+            vec![(1, 0), (9, 0), (2, 2), (6, 4)]
+        );
+        assert_eq!(map.basin_labels().get(4, 0), Some(&None));
+        assert_eq!(map.basin_labels().get(5, 0), Some(&Some(1)));
+        Ok(())
+    }
+
+    #[test]
+    fn wall_height() -> Result<()> {
+        let map = parse("1519\n1511\n1811")?;
+        assert_eq!(map.basins().len(), 1);
+
+        let map = map.with_wall_height(5);
+        assert_eq!(
+            map.basins().iter().map(Basin::size).collect::<Vec<_>>(),
+            vec![3, 5]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn render_basins() -> Result<()> {
+        let map = parse("219\n399")?;
+
+        assert_eq!(
+            map.render_ansi(),
+            format!(
+                "{}{}9\n{}99\n",
+                paint(2, palette(0)),
+                highlight(1),
+                paint(3, palette(0))
+            )
+        );
+        let image = map.render_image();
+        assert_eq!(image.get(1, 0), Some(WHITE));
+        assert_eq!(image.get(0, 1), Some(palette(0)));
+        assert_eq!(image.get(2, 1), Some([0; 3]));
+        Ok(())
+    }
+
+    #[test]
+    fn too_few_basins() -> Result<()> {
+        assert!(matches!(
+            part2(&parse("191\n999")?).err(),
+            Some(AocError::Unsolvable(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+// Everything that can go wrong while parsing or solving a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    // part of the input didn't look like it should, line and column are 1-based, 0 when unknown
+    Parse {
+        line: usize,
+        column: usize,
+        expected: String,
+        found: String,
+    },
+    // the input parsed but doesn't describe a puzzle that makes sense
+    MalformedInput(String),
+    // the input is fine but there is no answer to be found in it
+    Unsolvable(String),
+}
+
+impl AocError {
+    pub fn parse(expected: impl Into<String>, found: impl Into<String>) -> Self {
+        AocError::Parse {
+            line: 0,
+            column: 0,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    pub fn malformed(message: impl Into<String>) -> Self {
+        AocError::MalformedInput(message.into())
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        AocError::Unsolvable(message.into())
+    }
+
+    // attach the input line a parse error came from, other errors are left alone
+    pub fn at_line(mut self, line_number: usize) -> Self {
+        if let AocError::Parse { line, .. } = &mut self {
+            *line = line_number;
+        }
+        self
+    }
+
+    // attach the position within the line, offset by any column already set
+    pub fn at_column(mut self, column_number: usize) -> Self {
+        if let AocError::Parse { column, .. } = &mut self {
+            *column = match *column {
+                0 => column_number,
+                existing => existing + column_number - 1,
+            };
+        }
+        self
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Parse {
+                line,
+                column,
+                expected,
+                found,
+            } => {
+                if *line > 0 {
+                    write!(f, "line {}", line)?;
+                    if *column > 0 {
+                        write!(f, ", column {}", column)?;
+                    }
+                    write!(f, ": ")?;
+                } else if *column > 0 {
+                    write!(f, "column {}: ", column)?;
+                }
+
+                if found.is_empty() {
+                    write!(f, "expected {}, found nothing", expected)
+                } else {
+                    write!(f, "expected {}, found {:?}", expected, found)
+                }
+            }
+            AocError::MalformedInput(message) => write!(f, "malformed input: {}", message),
+            AocError::Unsolvable(message) => write!(f, "unsolvable: {}", message),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+// the lines of an input with their 1-based line numbers, trimmed of surrounding whitespace
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+}
+
+// parse a number out of part of a line, naming what it should have been if it isn't one
+pub fn parse_number<T: FromStr>(s: &str, expected: &str) -> Result<T, AocError> {
+    s.trim()
+        .parse::<T>()
+        .map_err(|_| AocError::parse(expected, s.trim()))
+}
+
+// parse a single decimal digit from a grid of them
+pub fn parse_digit(c: char, expected: &str) -> Result<u32, AocError> {
+    c.to_digit(10)
+        .ok_or_else(|| AocError::parse(expected, c.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_parse_errors() {
+        let err = AocError::parse("a digit", "x");
+        assert_eq!(err.to_string(), "expected a digit, found \"x\"");
+
+        let err = err.at_line(3);
+        assert_eq!(err.to_string(), "line 3: expected a digit, found \"x\"");
+
+        let err = err.at_column(5);
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 5: expected a digit, found \"x\""
+        );
+
+        let err = AocError::parse("a fold", "").at_line(2);
+        assert_eq!(err.to_string(), "line 2: expected a fold, found nothing");
+    }
+
+    #[test]
+    fn columns_are_offset() {
+        // a column found within part of a line moves along by where that part starts
+        let err = AocError::parse("a number", "x").at_column(3).at_column(10);
+
+        assert_eq!(
+            err,
+            AocError::Parse {
+                line: 0,
+                column: 12,
+                expected: String::from("a number"),
+                found: String::from("x"),
+            }
+        );
+    }
+
+    #[test]
+    fn other_errors_ignore_positions() {
+        let err = AocError::unsolvable("no path").at_line(4).at_column(2);
+
+        assert_eq!(err, AocError::Unsolvable(String::from("no path")));
+        assert_eq!(err.to_string(), "unsolvable: no path");
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(parse_number::<i32>(" -12 ", "a depth"), Ok(-12));
+        assert_eq!(
+            parse_number::<u8>("abc", "a position"),
+            Err(AocError::parse("a position", "abc"))
+        );
+        assert_eq!(parse_digit('7', "a height"), Ok(7));
+        assert!(parse_digit('x', "a height").is_err());
+    }
+}
@@ -0,0 +1,322 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::error::{numbered_lines, parse_digit, AocError};
+use crate::solution::Result;
+
+// up, right, down and left
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+// every surrounding position, row by row from the top left
+pub const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+// A rectangle of values, (0, 0) is the top left and rows are stored one after another
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        if cells.len() != width * height {
+            return Err(AocError::malformed(format!(
+                "a {}x{} grid can't hold {} values",
+                width,
+                height,
+                cells.len()
+            )));
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    // One value per character, every row the same width. Blank lines before and after the grid are
+    // skipped, cells names what the values are for error messages, e.g. "heights".
+    pub fn parse_chars(
+        input: &str,
+        cells: &str,
+        parse_cell: impl Fn(char) -> Result<T>,
+    ) -> Result<Self> {
+        let mut lines: Vec<(usize, &str)> = numbered_lines(input)
+            .skip_while(|(_, line)| line.is_empty())
+            .collect();
+        while lines.last().is_some_and(|(_, line)| line.is_empty()) {
+            lines.pop();
+        }
+
+        Self::from_lines(lines, cells, parse_cell)
+    }
+
+    // the same as parse_chars for lines that have already been numbered
+    pub fn from_lines<'a>(
+        lines: impl IntoIterator<Item = (usize, &'a str)>,
+        cells: &str,
+        parse_cell: impl Fn(char) -> Result<T>,
+    ) -> Result<Self> {
+        let mut grid = Self {
+            cells: Vec::new(),
+            width: 0,
+            height: 0,
+        };
+
+        for (line_number, line) in lines {
+            if grid.height == 0 {
+                grid.width = line.chars().count();
+            } else if line.chars().count() != grid.width {
+                return Err(
+                    AocError::parse(format!("a row of {} {}", grid.width, cells), line)
+                        .at_line(line_number),
+                );
+            }
+
+            for (x, c) in line.chars().enumerate() {
+                grid.cells
+                    .push(parse_cell(c).map_err(|err| err.at_line(line_number).at_column(x + 1))?);
+            }
+            grid.height += 1;
+        }
+
+        if grid.cells.is_empty() {
+            return Err(AocError::malformed(format!("there are no {}", cells)));
+        }
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    // for positions worked out with offsets that may have gone off the top or left
+    pub fn get_signed(&self, x: isize, y: isize) -> Option<&T> {
+        let (x, y) = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+        self.get(x, y)
+    }
+
+    // the position dx across and dy down from (x, y), if it's still on the grid
+    pub fn offset(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        offset_within(x, y, dx, dy, self.width, self.height)
+    }
+
+    // the positions above, right, below and left of (x, y) that are on the grid
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&(dx, dy)| self.offset(x, y, dx, dy))
+    }
+
+    // the positions around (x, y), diagonals included, that are on the grid
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        SURROUNDING
+            .iter()
+            .filter_map(move |&(dx, dy)| self.offset(x, y, dx, dy))
+    }
+
+    // every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    // every position with its value, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.cells[x..].iter().step_by(self.width))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl Grid<u32> {
+    // a grid of single digits, like a height map
+    pub fn parse_digits(input: &str, cells: &str, expected: &str) -> Result<Self> {
+        Self::parse_chars(input, cells, |c| parse_digit(c, expected))
+    }
+}
+
+// the position dx across and dy down from (x, y), if it's inside width and height
+pub fn offset_within(
+    x: usize,
+    y: usize,
+    dx: isize,
+    dy: isize,
+    width: usize,
+    height: usize,
+) -> Option<(usize, usize)> {
+    let x = x.checked_add_signed(dx).filter(|x| *x < width)?;
+    let y = y.checked_add_signed(dy).filter(|y| *y < height)?;
+    Some((x, y))
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(x < self.width, "x {} is off a grid {} wide", x, self.width);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(x < self.width, "x {} is off a grid {} wide", x, self.width);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+// each value written straight after the last, one row per line
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_digits() -> Result<()> {
+        let grid = Grid::parse_digits("\n123\n456\n\n", "heights", "a height")?;
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(2, 1), Some(&6));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid[(1, 0)], 2);
+        assert_eq!(grid.to_string(), "123\n456\n");
+        Ok(())
+    }
+
+    #[test]
+    fn bad_grids() {
+        assert_eq!(
+            Grid::parse_digits("\n123\n4x6", "heights", "a height").err(),
+            Some(AocError::parse("a height", "x").at_line(3).at_column(2))
+        );
+        assert_eq!(
+            Grid::parse_digits("123\n45", "heights", "a height").err(),
+            Some(AocError::parse("a row of 3 heights", "45").at_line(2))
+        );
+        assert_eq!(
+            Grid::parse_digits("\n\n", "heights", "a height").err(),
+            Some(AocError::malformed("there are no heights"))
+        );
+        assert!(Grid::new(2, 2, vec![1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn neighbours() -> Result<()> {
+        let grid = Grid::new(3, 2, vec![0; 6])?;
+
+        assert_eq!(
+            grid.neighbours4(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours4(1, 1).collect::<Vec<_>>(),
+            vec![(1, 0), (2, 1), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8(1, 0).count(), 5);
+        assert_eq!(grid.neighbours8(2, 1).count(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn rows_and_columns() -> Result<()> {
+        let mut grid = Grid::new(3, 2, vec![1, 2, 3, 4, 5, 6])?;
+        grid[(0, 1)] = 9;
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2, 3], &[9, 5, 6]]
+        );
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.sum::<i32>())
+                .collect::<Vec<_>>(),
+            vec![10, 7, 9]
+        );
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &5)));
+        assert_eq!(grid.map(|value| value % 2 == 0).get(1, 0), Some(&true));
+        Ok(())
+    }
+}
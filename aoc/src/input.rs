@@ -0,0 +1,228 @@
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+// environment variable naming a directory of dayNN.txt inputs
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+// passing this as the input path reads the puzzle input from stdin
+pub const STDIN_PATH: &str = "-";
+
+// where a puzzle's input comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            InputSource::File(path) => fs::read_to_string(path).map_err(|err| {
+                io::Error::new(
+                    err.kind(),
+                    format!("unable to read {}: {}", path.display(), err),
+                )
+            }),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+// Finds the input for a puzzle, looking in order for:
+//      an explicit path (or - for stdin)
+//      dayNN.txt in the directory named by AOC_INPUT_DIR
+//      inputs/dayNN.txt at the workspace root
+//      the legacy day_N/puzzle_M/input.txt at the workspace root
+// the first of the directory lookups that has a file wins
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputResolver {
+    explicit: Option<String>,
+    input_dir: Option<PathBuf>,
+    workspace_root: PathBuf,
+}
+
+impl InputResolver {
+    pub fn new(
+        explicit: Option<String>,
+        input_dir: Option<PathBuf>,
+        workspace_root: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            explicit,
+            input_dir,
+            workspace_root: workspace_root.into(),
+        }
+    }
+
+    // a resolver using AOC_INPUT_DIR and this crate's workspace, so the current directory doesn't
+    // matter
+    pub fn from_env(explicit: Option<String>) -> Self {
+        let input_dir = std::env::var_os(INPUT_DIR_VAR)
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from);
+        Self::new(explicit, input_dir, workspace_root())
+    }
+
+    pub fn resolve(&self, day: u8, part: u8) -> io::Result<InputSource> {
+        match self.explicit.as_deref() {
+            Some(STDIN_PATH) => return Ok(InputSource::Stdin),
+            Some(path) => return Ok(InputSource::File(PathBuf::from(path))),
+            None => (),
+        }
+
+        let candidates = self.candidates(day, part);
+        candidates
+            .iter()
+            .find(|path| path.is_file())
+            .map(|path| InputSource::File(path.clone()))
+            .ok_or_else(|| {
+                let tried = candidates
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("no input for day {} part {}, tried {}", day, part, tried),
+                )
+            })
+    }
+
+    // the files that could hold the input, in the order they're checked
+    pub fn candidates(&self, day: u8, part: u8) -> Vec<PathBuf> {
+        let file_name = day_file_name(day);
+        let mut candidates = Vec::new();
+
+        if let Some(input_dir) = &self.input_dir {
+            candidates.push(input_dir.join(&file_name));
+        }
+        candidates.push(self.workspace_root.join("inputs").join(&file_name));
+        candidates.push(
+            self.workspace_root
+                .join(format!("day_{}", day))
+                .join(format!("puzzle_{}", part))
+                .join("input.txt"),
+        );
+
+        candidates
+    }
+}
+
+// day 3 is day03.txt, so the files sort in order
+pub fn day_file_name(day: u8) -> String {
+    format!("day{:02}.txt", day)
+}
+
+// the aoc crate lives one level below the workspace root
+pub fn workspace_root() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.parent().unwrap_or(manifest_dir).to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a fresh directory for each test so they can run side by side
+    fn scratch_dir(name: &str) -> io::Result<PathBuf> {
+        let dir = std::env::temp_dir().join(format!("aoc_input_{}_{}", name, std::process::id()));
+        if dir.exists() {
+            fs::remove_dir_all(&dir)?;
+        }
+        fs::create_dir_all(&dir)?;
+        Ok(dir)
+    }
+
+    fn write_input(path: &Path) -> io::Result<()> {
+        fs::create_dir_all(path.parent().unwrap_or(path))?;
+        fs::write(path, "199\n200\n")
+    }
+
+    #[test]
+    fn explicit_path_wins() -> io::Result<()> {
+        let resolver = InputResolver::new(
+            Some(String::from("mine.txt")),
+            Some(PathBuf::from("somewhere")),
+            "root",
+        );
+
+        assert_eq!(
+            resolver.resolve(1, 1)?,
+            InputSource::File(PathBuf::from("mine.txt"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn dash_reads_stdin() -> io::Result<()> {
+        let resolver = InputResolver::new(Some(String::from("-")), None, "root");
+
+        assert_eq!(resolver.resolve(7, 2)?, InputSource::Stdin);
+        Ok(())
+    }
+
+    #[test]
+    fn candidate_order() {
+        let resolver = InputResolver::new(None, Some(PathBuf::from("alice")), "root");
+
+        assert_eq!(
+            resolver.candidates(3, 2),
+            vec![
+                PathBuf::from("alice/day03.txt"),
+                PathBuf::from("root/inputs/day03.txt"),
+                PathBuf::from("root/day_3/puzzle_2/input.txt"),
+            ]
+        );
+    }
+
+    #[test]
+    fn first_existing_file_is_used() -> io::Result<()> {
+        let root = scratch_dir("existing")?;
+        let input_dir = root.join("alice");
+        let legacy = root.join("day_15/puzzle_1/input.txt");
+        write_input(&legacy)?;
+
+        let resolver = InputResolver::new(None, Some(input_dir.clone()), &root);
+        assert_eq!(resolver.resolve(15, 1)?, InputSource::File(legacy));
+
+        let shared = root.join("inputs/day15.txt");
+        write_input(&shared)?;
+        assert_eq!(resolver.resolve(15, 1)?, InputSource::File(shared));
+
+        let alice = input_dir.join("day15.txt");
+        write_input(&alice)?;
+        assert_eq!(resolver.resolve(15, 1)?, InputSource::File(alice.clone()));
+        assert_eq!(resolver.resolve(15, 1)?.read()?, "199\n200\n");
+
+        fs::remove_dir_all(root)
+    }
+
+    #[test]
+    fn missing_input() -> io::Result<()> {
+        let root = scratch_dir("missing")?;
+        let resolver = InputResolver::new(None, None, &root);
+
+        let err = resolver.resolve(4, 1).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(err.to_string().contains("day04.txt"));
+
+        fs::remove_dir_all(root)
+    }
+}
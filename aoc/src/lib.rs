@@ -0,0 +1,264 @@
+pub mod answers;
+pub mod bench;
+pub mod bits;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod log;
+pub mod ocr;
+pub mod render;
+pub mod solution;
+
+pub mod day_1;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_2;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+
+use day_1::Day1;
+use day_10::Day10;
+use day_11::Day11;
+use day_12::Day12;
+use day_13::Day13;
+use day_14::Day14;
+use day_15::Day15;
+use day_16::Day16;
+use day_17::Day17;
+use day_18::Day18;
+use day_19::Day19;
+use day_2::Day2;
+use day_20::Day20;
+use day_21::Day21;
+use day_22::Day22;
+use day_3::Day3;
+use day_4::Day4;
+use day_5::Day5;
+use day_6::Day6;
+use day_7::Day7;
+use day_8::Day8;
+use day_9::Day9;
+use error::AocError;
+use log::info;
+use render::RenderFormat;
+use solution::{run_timed, Answer, Result, Timing};
+
+// every (day, part) that has a solver, in the order `run --all` works through them
+pub const PUZZLES: &[(u8, u8)] = &[
+    (1, 1),
+    (1, 2),
+    (2, 1),
+    (2, 2),
+    (3, 1),
+    (3, 2),
+    (4, 1),
+    (4, 2),
+    (5, 1),
+    (5, 2),
+    (6, 1),
+    (6, 2),
+    (7, 1),
+    (7, 2),
+    (8, 1),
+    (8, 2),
+    (9, 1),
+    (9, 2),
+    (10, 1),
+    (10, 2),
+    (11, 1),
+    (11, 2),
+    (12, 1),
+    (12, 2),
+    (13, 1),
+    (13, 2),
+    (14, 1),
+    (14, 2),
+    (15, 1),
+    (15, 2),
+    (16, 1),
+    (16, 2),
+    (17, 1),
+    (17, 2),
+    (18, 1),
+    (18, 2),
+    (19, 1),
+    (20, 1),
+    (20, 2),
+    (21, 1),
+    (21, 2),
+    (22, 1),
+    (22, 2),
+];
+
+// Runs the given day and part against the raw contents of an input file
+pub fn solve(day: u8, part: u8, raw_input: &str) -> Result<Answer> {
+    solve_timed(day, part, raw_input).map(|(answer, _)| answer)
+}
+
+// The same as solve, also returning how long parsing and solving took
+pub fn solve_timed(day: u8, part: u8, raw_input: &str) -> Result<(Answer, Timing)> {
+    let (answer, timing) = match day {
+        1 => run_timed::<Day1>(part, raw_input),
+        2 => run_timed::<Day2>(part, raw_input),
+        3 => run_timed::<Day3>(part, raw_input),
+        4 => run_timed::<Day4>(part, raw_input),
+        5 => run_timed::<Day5>(part, raw_input),
+        6 => run_timed::<Day6>(part, raw_input),
+        7 => run_timed::<Day7>(part, raw_input),
+        8 => run_timed::<Day8>(part, raw_input),
+        9 => run_timed::<Day9>(part, raw_input),
+        10 => run_timed::<Day10>(part, raw_input),
+        11 => run_timed::<Day11>(part, raw_input),
+        12 => run_timed::<Day12>(part, raw_input),
+        13 => run_timed::<Day13>(part, raw_input),
+        14 => run_timed::<Day14>(part, raw_input),
+        15 => run_timed::<Day15>(part, raw_input),
+        16 => run_timed::<Day16>(part, raw_input),
+        17 => run_timed::<Day17>(part, raw_input),
+        18 => run_timed::<Day18>(part, raw_input),
+        19 => run_timed::<Day19>(part, raw_input),
+        20 => run_timed::<Day20>(part, raw_input),
+        21 => run_timed::<Day21>(part, raw_input),
+        22 => run_timed::<Day22>(part, raw_input),
+        _ => Err(AocError::unsolvable(format!("No solution for day {}", day))),
+    }?;
+
+    info!(
+        "Day {} part {} parsed in {:?}, solved in {:?}",
+        day, part, timing.parse, timing.compute
+    );
+    Ok((answer, timing))
+}
+
+// Draws the given day's puzzle, for the days that have something worth looking at
+pub fn render(day: u8, part: u8, raw_input: &str, format: RenderFormat) -> Result<Vec<u8>> {
+    match day {
+        9 => day_9::render(&day_9::parse(raw_input)?, format),
+        12 => day_12::render(&day_12::parse(raw_input)?, format),
+        15 => day_15::render(&day_15::parse(raw_input)?, part, format),
+        _ => Err(AocError::unsolvable(format!(
+            "day {} has nothing to render",
+            day
+        ))),
+    }
+}
+
+// The worked example from each day's puzzle text, or one made up for a part when the puzzle's
+// example doesn't have an answer
+pub fn example_input(day: u8, part: u8) -> Option<&'static str> {
+    let example = match (day, part) {
+        // the puzzle's example folds into a square rather than letters
+        (13, 2) => include_str!("../example_inputs/day13_part2.txt"),
+        _ => return example_input_for_day(day),
+    };
+    Some(example)
+}
+
+fn example_input_for_day(day: u8) -> Option<&'static str> {
+    let example = match day {
+        1 => include_str!("../example_inputs/day01.txt"),
+        2 => include_str!("../example_inputs/day02.txt"),
+        3 => include_str!("../example_inputs/day03.txt"),
+        4 => include_str!("../example_inputs/day04.txt"),
+        5 => include_str!("../example_inputs/day05.txt"),
+        6 => include_str!("../example_inputs/day06.txt"),
+        7 => include_str!("../example_inputs/day07.txt"),
+        8 => include_str!("../example_inputs/day08.txt"),
+        9 => include_str!("../example_inputs/day09.txt"),
+        10 => include_str!("../example_inputs/day10.txt"),
+        11 => include_str!("../example_inputs/day11.txt"),
+        12 => include_str!("../example_inputs/day12.txt"),
+        13 => include_str!("../example_inputs/day13.txt"),
+        14 => include_str!("../example_inputs/day14.txt"),
+        15 => include_str!("../example_inputs/day15.txt"),
+        16 => include_str!("../example_inputs/day16.txt"),
+        17 => include_str!("../example_inputs/day17.txt"),
+        18 => include_str!("../example_inputs/day18.txt"),
+        19 => include_str!("../example_inputs/day19.txt"),
+        20 => include_str!("../example_inputs/day20.txt"),
+        21 => include_str!("../example_inputs/day21.txt"),
+        22 => include_str!("../example_inputs/day22.txt"),
+        _ => return None,
+    };
+    Some(example)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve_text_input() -> Result<()> {
+        let test_data = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+        assert_eq!(solve(1, 1, test_data)?, Answer::UInt(7));
+        assert_eq!(solve(1, 2, test_data)?, Answer::UInt(5));
+        Ok(())
+    }
+
+    #[test]
+    fn solve_line_input() -> Result<()> {
+        let test_data = "16,1,2,0,4,2,7,1,2,14\n";
+
+        assert_eq!(solve(7, 1, test_data)?, Answer::Int(37));
+        assert_eq!(solve(7, 2, test_data)?, Answer::Int(168));
+        Ok(())
+    }
+
+    #[test]
+    fn solve_parsed_parameters() -> Result<()> {
+        assert_eq!(
+            solve(17, 1, "target area: x=20..30, y=-10..-5\n")?,
+            Answer::Int(45)
+        );
+        assert_eq!(
+            solve(
+                21,
+                1,
+                "Player 1 starting position: 4\nPlayer 2 starting position: 8\n"
+            )?,
+            Answer::UInt(739785)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn solve_unknown_puzzle() {
+        assert!(solve(19, 2, "").is_err());
+        assert!(solve(26, 1, "").is_err());
+    }
+
+    #[test]
+    fn solve_examples() -> Result<()> {
+        for (day, part) in PUZZLES {
+            let example = example_input(*day, *part).expect("every puzzle has an example");
+            solve(*day, *part, example)?;
+        }
+        assert_eq!(
+            solve(5, 2, example_input(5, 2).unwrap_or_default())?,
+            Answer::UInt(12)
+        );
+        assert_eq!(
+            solve(13, 2, example_input(13, 2).unwrap_or_default())?,
+            Answer::from("AOC")
+        );
+        assert_eq!(example_input(23, 1), None);
+        Ok(())
+    }
+}
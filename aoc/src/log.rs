@@ -0,0 +1,259 @@
+use std::{
+    fmt::{Arguments, Display},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        RwLock,
+    },
+};
+
+use crate::error::AocError;
+use crate::solution::Result;
+
+// environment variable holding a log filter, e.g. AOC_LOG=info,day_15=trace
+pub const LOG_VAR: &str = "AOC_LOG";
+
+// How much a message matters, messages are written when their level is at or below the filter's
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 6] = [
+        Level::Off,
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ];
+
+    // one level more verbose per step, stopping at trace
+    pub fn raised_by(self, steps: u8) -> Level {
+        Self::ALL[(self as usize + steps as usize).min(Self::ALL.len() - 1)]
+    }
+}
+
+impl FromStr for Level {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|level| level.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| AocError::parse("off, error, warn, info, debug or trace", s.to_string()))
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Level::Off => "off",
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// Which messages get written, a default level plus overrides for single modules:
+//
+//      info,day_15=trace,day_21=off
+//
+// a module can be named by its number too, so 15=trace is the same as day_15=trace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    default: Level,
+    modules: Vec<(String, Level)>,
+}
+
+impl LogFilter {
+    pub const fn new(default: Level) -> Self {
+        Self {
+            default,
+            modules: Vec::new(),
+        }
+    }
+
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut filter = Self::default();
+        let mut column = 1;
+
+        for directive in spec.split(',') {
+            let parsed = match directive.trim().split_once('=') {
+                None if directive.trim().is_empty() => Ok(()),
+                None => directive.trim().parse().map(|level| filter.default = level),
+                Some((module, level)) => level
+                    .trim()
+                    .parse()
+                    .map(|level| filter.set_module(module.trim(), level)),
+            };
+            parsed.map_err(|err| err.at_column(column))?;
+            column += directive.len() + 1;
+        }
+
+        Ok(filter)
+    }
+
+    // the filter from AOC_LOG, or the default when it isn't set
+    pub fn from_env() -> Result<Self> {
+        match std::env::var(LOG_VAR) {
+            Ok(spec) => Self::parse(&spec),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn with_module(mut self, module: &str, level: Level) -> Self {
+        self.set_module(module, level);
+        self
+    }
+
+    fn set_module(&mut self, module: &str, level: Level) {
+        let module = match module.parse::<u8>() {
+            Ok(day) => format!("day_{}", day),
+            Err(_) => module.to_string(),
+        };
+        self.modules.retain(|(existing, _)| *existing != module);
+        self.modules.push((module, level));
+    }
+
+    // raise the default level, so -v shows info, -vv debug and -vvv trace
+    pub fn verbose(mut self, verbosity: u8) -> Self {
+        self.default = self.default.max(Level::Warn.raised_by(verbosity));
+        self
+    }
+
+    // the level for a module path like aoc::day_15, going by its last part
+    pub fn level(&self, module_path: &str) -> Level {
+        let module = module_path.rsplit("::").next().unwrap_or(module_path);
+        self.modules
+            .iter()
+            .find(|(name, _)| name == module)
+            .map(|(_, level)| *level)
+            .unwrap_or(self.default)
+    }
+
+    fn max_level(&self) -> Level {
+        self.modules
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Level::max)
+    }
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        Self::new(Level::Warn)
+    }
+}
+
+static FILTER: RwLock<LogFilter> = RwLock::new(LogFilter::new(Level::Warn));
+
+// the most verbose level any module has, so most messages are skipped without taking the lock
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+// replace the filter for every message logged from now on
+pub fn init(filter: LogFilter) {
+    MAX_LEVEL.store(filter.max_level() as u8, Ordering::Relaxed);
+    if let Ok(mut current) = FILTER.write() {
+        *current = filter;
+    }
+}
+
+pub fn enabled(level: Level, module_path: &str) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+        && FILTER
+            .read()
+            .map(|filter| level <= filter.level(module_path))
+            .unwrap_or(false)
+}
+
+// messages go to stderr so they never mix with the answers
+pub fn write(level: Level, module_path: &str, args: Arguments) {
+    let module = module_path.rsplit("::").next().unwrap_or(module_path);
+    eprintln!("[{} {}] {}", level.to_string().to_uppercase(), module, args);
+}
+
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+pub(crate) use {debug, info, log, trace};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_levels() -> Result<()> {
+        assert_eq!("DEBUG".parse::<Level>()?, Level::Debug);
+        assert_eq!(Level::Warn.raised_by(2), Level::Debug);
+        assert_eq!(Level::Warn.raised_by(9), Level::Trace);
+        assert_eq!(
+            "loud".parse::<Level>().err(),
+            Some(AocError::parse(
+                "off, error, warn, info, debug or trace",
+                "loud"
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_filter() -> Result<()> {
+        let filter = LogFilter::parse("info, day_15=trace,21=off")?;
+
+        assert_eq!(filter.level("aoc::day_15"), Level::Trace);
+        assert_eq!(filter.level("aoc::day_21"), Level::Off);
+        assert_eq!(filter.level("aoc::day_1"), Level::Info);
+        assert_eq!(filter.max_level(), Level::Trace);
+        assert_eq!(LogFilter::parse("")?, LogFilter::default());
+        Ok(())
+    }
+
+    #[test]
+    fn bad_filter() {
+        assert_eq!(
+            LogFilter::parse("info,day_4=noisy").err(),
+            Some(AocError::parse("off, error, warn, info, debug or trace", "noisy").at_column(6))
+        );
+    }
+
+    #[test]
+    fn verbosity() -> Result<()> {
+        assert_eq!(LogFilter::default().verbose(0).level("day_1"), Level::Warn);
+        assert_eq!(LogFilter::default().verbose(1).level("day_1"), Level::Info);
+        assert_eq!(LogFilter::default().verbose(3).level("day_1"), Level::Trace);
+        // -v never quietens an explicit filter
+        assert_eq!(
+            LogFilter::parse("debug")?.verbose(1).level("day_1"),
+            Level::Debug
+        );
+        Ok(())
+    }
+}
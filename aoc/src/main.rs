@@ -0,0 +1,568 @@
+use std::{
+    env,
+    error::Error,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use aoc::answers::{self, AnswerKey, Outcome, Verification, ANSWERS_FILE};
+use aoc::bench::{self, BenchConfig, BenchReport, DEFAULT_THRESHOLD, EXAMPLE_LABEL};
+use aoc::input::{workspace_root, InputResolver, InputSource};
+use aoc::log::{self as aoc_log, LogFilter, LOG_VAR};
+use aoc::render::RenderFormat;
+use aoc::solution::Answer;
+use aoc::{example_input, render, solve, PUZZLES};
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+const BENCH_REPORT_FILE: &str = "bench_report.csv";
+const BENCH_BASELINE_FILE: &str = "bench_baseline.csv";
+
+const USAGE: &str = "Usage:
+    aoc run --day <DAY> [--part <PART>] [--input <PATH>]
+    aoc run --all
+    aoc verify [--day <DAY>] [--part <PART>] [--input <PATH>] [--answers <PATH>] [--record]
+    aoc bench [--day <DAY>] [--part <PART>] [--input <PATH>] [--iterations <N>]
+              [--report <PATH>] [--baseline <PATH>] [--threshold <PERCENT>] [--save-baseline]
+    aoc render --day <DAY> [--part <PART>] [--input <PATH>] [--output <PATH>] [--dot]
+
+verify checks every answer against answers.toml at the workspace root, --record
+adds the answers that aren't there yet.
+
+bench times every puzzle on its example and on the real input when there is one,
+writes the results to target/bench_report.csv and fails if anything got more than
+--threshold percent (default 20) slower than bench_baseline.csv. --save-baseline
+replaces the baseline with this run.
+
+render draws a day's puzzle in the terminal, or as an image when --output ends in .ppm.
+--dot, or --output ending in .dot or .gv, writes a Graphviz graph for days like 12.
+
+-v, -vv and -vvv log more of what the solvers are doing, --log <FILTER> picks levels
+per day, e.g. --log info,day_15=trace. $AOC_LOG is used when --log isn't given.
+
+--input - reads the input from stdin. Without --input each puzzle looks for
+$AOC_INPUT_DIR/dayNN.txt, then inputs/dayNN.txt, then day_N/puzzle_M/input.txt";
+
+fn main() -> Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let run_args = match RunArgs::from_args(&args) {
+        Ok(run_args) => run_args,
+        Err(err) => {
+            eprintln!("{}", USAGE);
+            return Err(err);
+        }
+    };
+
+    let filter = match &run_args.log {
+        Some(spec) => LogFilter::parse(spec).map_err(|err| format!("--log: {}", err))?,
+        None => LogFilter::from_env().map_err(|err| format!("{}: {}", LOG_VAR, err))?,
+    };
+    aoc_log::init(filter.verbose(run_args.verbosity));
+
+    match run_args.command {
+        Command::Run => run(&run_args),
+        Command::Verify => verify(&run_args),
+        Command::Bench => bench(&run_args),
+        Command::Render => render_puzzle(&run_args),
+    }
+}
+
+fn run(run_args: &RunArgs) -> Result<()> {
+    let resolver = InputResolver::from_env(run_args.input.clone());
+    let mut stdin_input = None;
+
+    let mut failures = 0;
+    for (day, part) in run_args.puzzles() {
+        match run_puzzle(day, part, &resolver, &mut stdin_input) {
+            Ok(output) => println!("Day {} part {}: {}", day, part, output),
+            Err(err) => {
+                println!("Day {} part {}: error: {}", day, part, err);
+                failures += 1;
+            }
+        }
+    }
+
+    if failures > 0 {
+        return Err(format!("{} puzzle(s) failed", failures).into());
+    }
+    Ok(())
+}
+
+fn run_puzzle(
+    day: u8,
+    part: u8,
+    resolver: &InputResolver,
+    stdin_input: &mut Option<String>,
+) -> Result<Answer> {
+    let raw_input = read_input(day, part, resolver, stdin_input)?;
+    Ok(solve(day, part, &raw_input)?)
+}
+
+// stdin can only be read once, so it's kept around for the other part of the day
+fn read_input(
+    day: u8,
+    part: u8,
+    resolver: &InputResolver,
+    stdin_input: &mut Option<String>,
+) -> Result<String> {
+    Ok(match resolver.resolve(day, part)? {
+        InputSource::Stdin => match stdin_input {
+            Some(input) => input.clone(),
+            None => stdin_input.insert(InputSource::Stdin.read()?).clone(),
+        },
+        source => source.read()?,
+    })
+}
+
+// solve every selected puzzle and compare it with the answer recorded for its input
+fn verify(run_args: &RunArgs) -> Result<()> {
+    let answers_path = run_args
+        .answers
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| workspace_root().join(ANSWERS_FILE));
+    let mut key = AnswerKey::load(&answers_path)?;
+
+    let resolver = InputResolver::from_env(run_args.input.clone());
+    let mut stdin_input = None;
+
+    let mut verifications = Vec::new();
+    for (day, part) in run_args.puzzles() {
+        let verification = match read_input(day, part, &resolver, &mut stdin_input) {
+            Ok(raw_input) => Verification::check(
+                day,
+                part,
+                &answers::input_hash(&raw_input),
+                solve(day, part, &raw_input),
+                &key,
+            ),
+            Err(err) => Verification {
+                day,
+                part,
+                input: String::from("-"),
+                outcome: Outcome::Error(err.to_string()),
+            },
+        };
+        verifications.push(verification);
+    }
+
+    println!("{}", answers::format_table(&verifications));
+
+    if run_args.record {
+        let mut recorded = 0;
+        for verification in &verifications {
+            if let Outcome::Missing { actual } = &verification.outcome {
+                key.record(
+                    verification.day,
+                    verification.part,
+                    &verification.input,
+                    &Answer::Text(actual.clone()),
+                );
+                recorded += 1;
+            }
+        }
+        key.save(&answers_path)?;
+        println!(
+            "Recorded {} answer(s) in {}",
+            recorded,
+            answers_path.display()
+        );
+    }
+
+    let failures = verifications
+        .iter()
+        .filter(|verification| verification.outcome.is_failure())
+        .count();
+    if failures > 0 {
+        return Err(format!("{} puzzle(s) failed verification", failures).into());
+    }
+    Ok(())
+}
+
+// time every selected puzzle, save the report and compare it with the baseline
+fn bench(run_args: &RunArgs) -> Result<()> {
+    let root = workspace_root();
+    let report_path = run_args
+        .report
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| root.join("target").join(BENCH_REPORT_FILE));
+    let baseline_path = run_args
+        .baseline
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| root.join(BENCH_BASELINE_FILE));
+
+    let mut config = BenchConfig::default();
+    if let Some(iterations) = run_args.iterations {
+        config.iterations = iterations;
+    }
+
+    let resolver = InputResolver::from_env(run_args.input.clone());
+    let mut stdin_input = None;
+
+    let mut report = BenchReport::default();
+    let mut failures = 0;
+    for (day, part) in run_args.puzzles() {
+        let mut inputs = Vec::new();
+        if let Some(example) = example_input(day, part) {
+            inputs.push((String::from(EXAMPLE_LABEL), example.to_string()));
+        }
+        // not everyone has every real input, so one that wasn't found is only skipped, but
+        // one that was asked for with --input has to be there
+        match read_input(day, part, &resolver, &mut stdin_input) {
+            Ok(raw_input) => inputs.push((answers::input_hash(&raw_input), raw_input)),
+            Err(err) if run_args.input.is_some() => {
+                println!("Day {} part {}: error: {}", day, part, err);
+                failures += 1;
+            }
+            Err(_) => {}
+        }
+
+        for (label, raw_input) in inputs {
+            match bench::bench(day, part, &label, &raw_input, &config) {
+                Ok(result) => report.results.push(result),
+                Err(err) => {
+                    println!("Day {} part {} ({}): error: {}", day, part, label, err);
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    println!("{}", bench::format_table(&report.results));
+    report.save(&report_path)?;
+    println!("Wrote {}", report_path.display());
+
+    if run_args.save_baseline {
+        report.save(&baseline_path)?;
+        println!("Saved the baseline to {}", baseline_path.display());
+    } else if baseline_path.is_file() {
+        let baseline = BenchReport::load(&baseline_path)?;
+        let threshold = run_args
+            .threshold
+            .map(|percent| percent / 100.0)
+            .unwrap_or(DEFAULT_THRESHOLD);
+
+        let regressions = report.regressions(&baseline, threshold);
+        for regression in &regressions {
+            println!("Regression: {}", regression);
+        }
+        failures += regressions.len();
+    }
+
+    if failures > 0 {
+        return Err(format!("{} puzzle(s) failed or regressed", failures).into());
+    }
+    Ok(())
+}
+
+// draw a single puzzle to stdout or the --output file
+fn render_puzzle(run_args: &RunArgs) -> Result<()> {
+    let day = run_args.day.ok_or("render needs a --day")?;
+    let part = run_args.part.unwrap_or(1);
+
+    let resolver = InputResolver::from_env(run_args.input.clone());
+    let raw_input = read_input(day, part, &resolver, &mut None)?;
+
+    match &run_args.output {
+        Some(output) => {
+            let output = Path::new(output);
+            let format = if run_args.dot {
+                RenderFormat::Dot
+            } else {
+                RenderFormat::for_path(output)
+            };
+            fs::write(output, render(day, part, &raw_input, format)?)?;
+            println!("Wrote {}", output.display());
+        }
+        None => {
+            let format = if run_args.dot {
+                RenderFormat::Dot
+            } else {
+                RenderFormat::Ansi
+            };
+            io::stdout().write_all(&render(day, part, &raw_input, format)?)?
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Default, PartialEq)]
+enum Command {
+    #[default]
+    Run,
+    Verify,
+    Bench,
+    Render,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct RunArgs {
+    command: Command,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    answers: Option<String>,
+    all: bool,
+    record: bool,
+    iterations: Option<u32>,
+    report: Option<String>,
+    baseline: Option<String>,
+    threshold: Option<f64>,
+    save_baseline: bool,
+    verbosity: u8,
+    log: Option<String>,
+    output: Option<String>,
+    dot: bool,
+}
+
+impl RunArgs {
+    fn from_args(args: &[String]) -> Result<Self> {
+        let mut iter = args.iter();
+
+        let command = match iter.next().map(String::as_str) {
+            Some("run") => Command::Run,
+            Some("verify") => Command::Verify,
+            Some("bench") => Command::Bench,
+            Some("render") => Command::Render,
+            Some(command) => return Err(format!("Unknown command: {}", command).into()),
+            None => return Err("Missing command".into()),
+        };
+
+        let mut run_args = Self {
+            command,
+            ..Self::default()
+        };
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--day" => run_args.day = Some(next_value(&mut iter, arg)?.parse()?),
+                "--part" => run_args.part = Some(next_value(&mut iter, arg)?.parse()?),
+                "--input" => run_args.input = Some(next_value(&mut iter, arg)?.to_string()),
+                "--all" => run_args.all = true,
+                "-v" | "--verbose" => run_args.verbosity += 1,
+                "-vv" => run_args.verbosity += 2,
+                "-vvv" => run_args.verbosity += 3,
+                "--log" => run_args.log = Some(next_value(&mut iter, arg)?.to_string()),
+                "--answers" if run_args.command == Command::Verify => {
+                    run_args.answers = Some(next_value(&mut iter, arg)?.to_string())
+                }
+                "--record" if run_args.command == Command::Verify => run_args.record = true,
+                "--iterations" if run_args.command == Command::Bench => {
+                    run_args.iterations = Some(next_value(&mut iter, arg)?.parse()?)
+                }
+                "--report" if run_args.command == Command::Bench => {
+                    run_args.report = Some(next_value(&mut iter, arg)?.to_string())
+                }
+                "--baseline" if run_args.command == Command::Bench => {
+                    run_args.baseline = Some(next_value(&mut iter, arg)?.to_string())
+                }
+                "--threshold" if run_args.command == Command::Bench => {
+                    run_args.threshold = Some(next_value(&mut iter, arg)?.parse()?)
+                }
+                "--save-baseline" if run_args.command == Command::Bench => {
+                    run_args.save_baseline = true
+                }
+                "--output" if run_args.command == Command::Render => {
+                    run_args.output = Some(next_value(&mut iter, arg)?.to_string())
+                }
+                "--dot" if run_args.command == Command::Render => run_args.dot = true,
+                _ => return Err(format!("Unknown argument: {}", arg).into()),
+            }
+        }
+
+        if run_args.all && (run_args.day.is_some() || run_args.part.is_some()) {
+            return Err("--all can't be combined with --day or --part".into());
+        }
+        if run_args.day.is_none() && run_args.input.is_some() {
+            return Err("--input needs a single --day".into());
+        }
+        if run_args.iterations == Some(0) {
+            return Err("--iterations must be at least 1".into());
+        }
+        if run_args.threshold.is_some_and(|threshold| threshold < 0.0) {
+            return Err("--threshold can't be negative".into());
+        }
+        // verify and bench check everything unless they're given a day
+        if matches!(run_args.command, Command::Run | Command::Render)
+            && !run_args.all
+            && run_args.day.is_none()
+        {
+            return Err("Either --day or --all is required".into());
+        }
+        if let Some(part) = run_args.part {
+            if part != 1 && part != 2 {
+                return Err(format!("Invalid part: {}", part).into());
+            }
+        }
+        if let Some(day) = run_args.day {
+            if !PUZZLES.iter().any(|(puzzle_day, _)| *puzzle_day == day) {
+                return Err(format!("There's no solution for day {}", day).into());
+            }
+        }
+
+        Ok(run_args)
+    }
+
+    // the (day, part) pairs selected by the arguments
+    fn puzzles(&self) -> Vec<(u8, u8)> {
+        match (self.day, self.part) {
+            (Some(day), Some(part)) => vec![(day, part)],
+            (Some(day), None) => PUZZLES
+                .iter()
+                .filter(|(puzzle_day, _)| *puzzle_day == day)
+                .cloned()
+                .collect(),
+            _ => PUZZLES.to_vec(),
+        }
+    }
+}
+
+fn next_value<'a>(iter: &mut std::slice::Iter<'a, String>, arg: &str) -> Result<&'a str> {
+    iter.next()
+        .map(String::as_str)
+        .ok_or_else(|| format!("Missing value for {}", arg).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_single_puzzle() -> Result<()> {
+        let run_args = RunArgs::from_args(&to_args(&[
+            "run", "--day", "15", "--part", "2", "--input", "path",
+        ]))?;
+
+        assert_eq!(run_args.puzzles(), vec![(15, 2)]);
+        assert_eq!(run_args.input.as_deref(), Some("path"));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_whole_day() -> Result<()> {
+        let run_args = RunArgs::from_args(&to_args(&["run", "--day", "19"]))?;
+
+        assert_eq!(run_args.puzzles(), vec![(19, 1)]);
+        assert_eq!(run_args.input, None);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_stdin() -> Result<()> {
+        let run_args = RunArgs::from_args(&to_args(&["run", "--day", "6", "--input", "-"]))?;
+
+        assert_eq!(
+            InputResolver::from_env(run_args.input).resolve(6, 1)?,
+            InputSource::Stdin
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_all() -> Result<()> {
+        let run_args = RunArgs::from_args(&to_args(&["run", "--all"]))?;
+
+        assert_eq!(run_args.puzzles().len(), PUZZLES.len());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_verify() -> Result<()> {
+        let run_args = RunArgs::from_args(&to_args(&["verify", "--record"]))?;
+        assert_eq!(run_args.command, Command::Verify);
+        assert!(run_args.record);
+        assert_eq!(run_args.puzzles().len(), PUZZLES.len());
+
+        let run_args = RunArgs::from_args(&to_args(&[
+            "verify",
+            "--day",
+            "18",
+            "--answers",
+            "mine.toml",
+        ]))?;
+        assert_eq!(run_args.puzzles(), vec![(18, 1), (18, 2)]);
+        assert_eq!(run_args.answers.as_deref(), Some("mine.toml"));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_logging() -> Result<()> {
+        let run_args = RunArgs::from_args(&to_args(&["run", "--day", "15", "-v", "-vv"]))?;
+        assert_eq!(run_args.verbosity, 3);
+
+        let run_args = RunArgs::from_args(&to_args(&["run", "--all", "--log", "day_15=trace"]))?;
+        assert_eq!(run_args.log.as_deref(), Some("day_15=trace"));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_bench() -> Result<()> {
+        let run_args = RunArgs::from_args(&to_args(&[
+            "bench",
+            "--day",
+            "15",
+            "--iterations",
+            "3",
+            "--threshold",
+            "50",
+            "--save-baseline",
+        ]))?;
+
+        assert_eq!(run_args.command, Command::Bench);
+        assert_eq!(run_args.puzzles(), vec![(15, 1), (15, 2)]);
+        assert_eq!(run_args.iterations, Some(3));
+        assert_eq!(run_args.threshold, Some(50.0));
+        assert!(run_args.save_baseline);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_render() -> Result<()> {
+        let run_args = RunArgs::from_args(&to_args(&[
+            "render",
+            "--day",
+            "15",
+            "--part",
+            "2",
+            "--output",
+            "day15.ppm",
+        ]))?;
+
+        assert_eq!(run_args.command, Command::Render);
+        assert_eq!(run_args.puzzles(), vec![(15, 2)]);
+        assert_eq!(run_args.output.as_deref(), Some("day15.ppm"));
+        assert!(!run_args.dot);
+
+        let run_args = RunArgs::from_args(&to_args(&["render", "--day", "12", "--dot"]))?;
+        assert!(run_args.dot);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(RunArgs::from_args(&to_args(&[])).is_err());
+        assert!(RunArgs::from_args(&to_args(&["run"])).is_err());
+        assert!(RunArgs::from_args(&to_args(&["run", "--day"])).is_err());
+        assert!(RunArgs::from_args(&to_args(&["run", "--day", "1", "--part", "3"])).is_err());
+        assert!(RunArgs::from_args(&to_args(&["run", "--all", "--input", "path"])).is_err());
+        assert!(RunArgs::from_args(&to_args(&["run", "--day", "1", "--record"])).is_err());
+        assert!(RunArgs::from_args(&to_args(&["verify", "--input", "path"])).is_err());
+        assert!(RunArgs::from_args(&to_args(&["verify", "--save-baseline"])).is_err());
+        assert!(RunArgs::from_args(&to_args(&["bench", "--iterations", "0"])).is_err());
+        assert!(RunArgs::from_args(&to_args(&["render", "--output", "day15.ppm"])).is_err());
+        assert!(RunArgs::from_args(&to_args(&["run", "--day", "15", "--output", "x"])).is_err());
+        assert!(RunArgs::from_args(&to_args(&["bench", "--threshold", "-5"])).is_err());
+        assert!(RunArgs::from_args(&to_args(&["run", "--day", "12", "--dot"])).is_err());
+        assert!(RunArgs::from_args(&to_args(&["run", "--day", "26"])).is_err());
+        assert!(RunArgs::from_args(&to_args(&["run", "--day", "0", "--input", "x"])).is_err());
+        assert!(RunArgs::from_args(&to_args(&["verify", "--day", "26"])).is_err());
+        assert!(RunArgs::from_args(&to_args(&["bench", "--day", "26", "--part", "1"])).is_err());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::solution::Result;
+
+// letters are drawn 4 dots wide and 6 tall, with an empty column after each one
+pub const LETTER_WIDTH: usize = 4;
+pub const LETTER_HEIGHT: usize = 6;
+const LETTER_SPACING: usize = LETTER_WIDTH + 1;
+
+// every letter that turns up in puzzle answers, row by row from the top left
+const FONT: [(char, &str); 16] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+// Reads the letters drawn by the dots that are set, left to right
+pub fn read(dots: &Grid<bool>) -> Result<String> {
+    if dots.height() > LETTER_HEIGHT {
+        return Err(AocError::unsolvable(format!(
+            "the dots are {} rows tall, letters are {}",
+            dots.height(),
+            LETTER_HEIGHT
+        )));
+    }
+
+    let letters = dots.width().div_ceil(LETTER_SPACING);
+    (0..letters)
+        .map(|letter| {
+            let glyph = glyph(dots, letter * LETTER_SPACING);
+            FONT.iter()
+                .find(|(_, known)| *known == glyph)
+                .map(|(c, _)| *c)
+                .ok_or_else(|| {
+                    AocError::unsolvable(format!(
+                        "letter {} isn't one that's known:\n{}",
+                        letter + 1,
+                        bitmap(&glyph)
+                    ))
+                })
+        })
+        .collect()
+}
+
+// the dots of the letter starting at column x, in the same form as FONT
+fn glyph(dots: &Grid<bool>, x: usize) -> String {
+    (0..LETTER_HEIGHT)
+        .flat_map(|y| (x..x + LETTER_WIDTH).map(move |x| (x, y)))
+        .map(|(x, y)| match dots.get(x, y) {
+            Some(true) => '#',
+            _ => '.',
+        })
+        .collect()
+}
+
+// a glyph back in rows, for showing a letter that couldn't be read
+fn bitmap(glyph: &str) -> String {
+    glyph
+        .as_bytes()
+        .chunks(LETTER_WIDTH)
+        .map(|row| String::from_utf8_lossy(row).into_owned())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dots(plot: &str) -> Result<Grid<bool>> {
+        Grid::parse_chars(plot, "dots", |c| Ok(c == '#'))
+    }
+
+    #[test]
+    fn read_letters() -> Result<()> {
+        let plot = "
+#..#.###...##.
+#..#.#..#.#..#
+####.###..#...
+#..#.#..#.#...
+#..#.#..#.#..#
+#..#.###...##.
+";
+        assert_eq!(read(&dots(plot)?)?, "HBC");
+        assert_eq!(read(&Grid::filled(0, 0, false))?, "");
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_letters() -> Result<()> {
+        let plot = "
+####.#####
+#..#.#...#
+#..#.#...#
+#..#.#...#
+####.#####
+";
+        assert_eq!(
+            read(&dots(plot)?).err(),
+            Some(AocError::unsolvable(
+                "letter 1 isn't one that's known:\n####\n#..#\n#..#\n#..#\n####\n...."
+            ))
+        );
+        assert!(read(&Grid::filled(4, 7, true)).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use std::{fmt::Display, path::Path};
+
+// bold red, for picking out part of a map drawn in the terminal
+pub const HIGHLIGHT: &str = "\x1b[1;31m";
+pub const RESET: &str = "\x1b[0m";
+
+// how a day draws its puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderFormat {
+    // text for a terminal, with ANSI colours
+    Ansi,
+    // a binary PPM image
+    Ppm,
+    // Graphviz source, for puzzles that are graphs rather than maps
+    Dot,
+}
+
+impl RenderFormat {
+    // images for .ppm files, graphs for .dot and .gv files, text for anything else
+    pub fn for_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("ppm") => RenderFormat::Ppm,
+            Some(extension)
+                if extension.eq_ignore_ascii_case("dot")
+                    || extension.eq_ignore_ascii_case("gv") =>
+            {
+                RenderFormat::Dot
+            }
+            _ => RenderFormat::Ansi,
+        }
+    }
+}
+
+pub fn highlight(text: impl Display) -> String {
+    format!("{}{}{}", HIGHLIGHT, text, RESET)
+}
+
+pub type Colour = [u8; 3];
+
+pub const RED: Colour = [255, 0, 0];
+pub const WHITE: Colour = [255; 3];
+
+// colours that are easy to tell apart, for telling areas of a map apart
+const PALETTE: [Colour; 8] = [
+    [230, 159, 0],
+    [86, 180, 233],
+    [0, 158, 115],
+    [240, 228, 66],
+    [0, 114, 178],
+    [213, 94, 0],
+    [204, 121, 167],
+    [153, 153, 255],
+];
+
+// a colour for the nth area, going back round the palette once it's used up
+pub fn palette(n: usize) -> Colour {
+    PALETTE[n % PALETTE.len()]
+}
+
+// text in a 24 bit terminal colour
+pub fn paint(text: impl Display, [r, g, b]: Colour) -> String {
+    format!("\x1b[38;2;{};{};{}m{}{}", r, g, b, text, RESET)
+}
+
+// a level out of max as a shade of grey, 0 is black and max is white
+pub fn grey(level: u32, max: u32) -> Colour {
+    let shade = (level.min(max) * 255 / max.max(1)) as u8;
+    [shade; 3]
+}
+
+// An image that's one pixel per position of a puzzle's map
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Colour>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![[0; 3]; width * height],
+        }
+    }
+
+    // pixels off the image are ignored
+    pub fn set(&mut self, x: usize, y: usize, colour: Colour) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = colour;
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Colour> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    // a binary PPM, which most image viewers open and which needs no compression
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend(self.pixels.iter().flatten());
+        ppm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats() {
+        assert_eq!(
+            RenderFormat::for_path(Path::new("out/day15.PPM")),
+            RenderFormat::Ppm
+        );
+        assert_eq!(
+            RenderFormat::for_path(Path::new("day15.txt")),
+            RenderFormat::Ansi
+        );
+        assert_eq!(
+            RenderFormat::for_path(Path::new("day12.gv")),
+            RenderFormat::Dot
+        );
+    }
+
+    #[test]
+    fn ppm() {
+        let mut image = Image::new(2, 1);
+        image.set(1, 0, RED);
+        image.set(2, 0, RED);
+
+        assert_eq!(image.get(0, 0), Some([0, 0, 0]));
+        assert_eq!(image.get(1, 1), None);
+        assert_eq!(
+            image.to_ppm(),
+            b"P6\n2 1\n255\n\x00\x00\x00\xff\x00\x00".to_vec()
+        );
+        assert_eq!(grey(9, 9), WHITE);
+        assert_eq!(palette(9), palette(1));
+        assert_eq!(paint("x", RED), "\x1b[38;2;255;0;0mx\x1b[0m");
+        assert_eq!(grey(0, 9), [0; 3]);
+    }
+}
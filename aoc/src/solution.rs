@@ -0,0 +1,172 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::error::AocError;
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+// The answer to a single part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::UInt(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Int(value.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Int(value)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::UInt(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::UInt(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::UInt(value as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+// A day's puzzle: parse the input once, then either part can be solved from it
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Answer>;
+
+    fn part2(input: &Self::Input) -> Result<Answer>;
+}
+
+// how long each phase of solving a puzzle took
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timing {
+    pub parse: Duration,
+    pub compute: Duration,
+}
+
+impl Timing {
+    pub fn total(&self) -> Duration {
+        self.parse + self.compute
+    }
+}
+
+// parse the raw input and solve the requested part of it
+pub fn run<S: Solution>(part: u8, raw_input: &str) -> Result<Answer> {
+    run_timed::<S>(part, raw_input).map(|(answer, _)| answer)
+}
+
+// the same as run, also timing the parse and the part separately
+pub fn run_timed<S: Solution>(part: u8, raw_input: &str) -> Result<(Answer, Timing)> {
+    if part != 1 && part != 2 {
+        return Err(AocError::unsolvable(format!("There is no part {}", part)));
+    }
+
+    let start = Instant::now();
+    let input = S::parse(raw_input)?;
+    let parsed = Instant::now();
+
+    let answer = match part {
+        1 => S::part1(&input),
+        _ => S::part2(&input),
+    }?;
+
+    Ok((
+        answer,
+        Timing {
+            parse: parsed - start,
+            compute: parsed.elapsed(),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        type Input = Vec<String>;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.lines().map(|line| line.to_string()).collect())
+        }
+
+        fn part1(input: &Self::Input) -> Result<Answer> {
+            Ok(input.len().into())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Answer> {
+            Ok(input.concat().into())
+        }
+    }
+
+    #[test]
+    fn run_parts() -> Result<()> {
+        assert_eq!(run::<Lengths>(1, "ab\ncd")?, Answer::UInt(2));
+        assert_eq!(
+            run::<Lengths>(2, "ab\ncd")?,
+            Answer::Text(String::from("abcd"))
+        );
+        assert!(run::<Lengths>(3, "ab\ncd").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn run_timed_parts() -> Result<()> {
+        let (answer, timing) = run_timed::<Lengths>(1, "ab\ncd")?;
+
+        assert_eq!(answer, Answer::UInt(2));
+        assert_eq!(timing.total(), timing.parse + timing.compute);
+        assert!(run_timed::<Lengths>(0, "ab\ncd").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn display_answers() {
+        assert_eq!(Answer::from(-3).to_string(), "-3");
+        assert_eq!(Answer::from(26984457539_u64).to_string(), "26984457539");
+        assert_eq!(Answer::from("ABC").to_string(), "ABC");
+    }
+}
@@ -1,60 +0,0 @@
-use std::{error::Error, fs};
-
-const INPUT_PATH: &str = "input.txt";
-
-fn main() -> Result<(), Box<dyn Error>> {
-    let raw_input = fs::read_to_string(INPUT_PATH)?;
-    let input: Vec<&str> = raw_input.lines().map(|line| line.trim()).collect();
-
-    let output = compute(&input)?;
-
-    println!("Puzzle output: {}", output);
-    Ok(())
-}
-
-fn compute(input: &[&str]) -> Result<i32, Box<dyn Error>> {
-    let mut signal_patterns: Vec<Vec<&str>> = Vec::new();
-    let mut output_values: Vec<Vec<&str>> = Vec::new();
-
-    for line in input {
-        let mut iter = line.split('|');
-        let raw_patterns = iter.next().ok_or_else(|| String::from("Invalid input"))?;
-        let raw_output = iter.next().ok_or_else(|| String::from("Invalid input"))?;
-
-        signal_patterns.push(raw_patterns.split_whitespace().collect::<Vec<&str>>());
-        output_values.push(raw_output.split_whitespace().collect::<Vec<&str>>());
-    }
-
-    let mut count = 0;
-    for output_line in output_values {
-        for val in output_line {
-            match val.chars().count() {
-                2 | 4 | 3 | 7 => count += 1,
-                _ => (),
-            }
-        }
-    }
-    Ok(count)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn example1() {
-        let test_data = vec![
-"be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe",
-"edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc",
-"fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg",
-"fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb",
-"aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea",
-"fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb",
-"dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe",
-"bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef",
-"egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb",
-"gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce",
-                                     ];
-
-        assert_eq!(compute(&test_data).unwrap(), 26)
-    }
-}